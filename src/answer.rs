@@ -0,0 +1,76 @@
+use std::fmt;
+
+/// A puzzle answer. Every day's parts convert into one of these, so runners
+/// and the answers file treat numbers, words and rendered screens alike.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    /// Wide enough for any `u64` or `i64` a day produces.
+    Int(i128),
+    Text(String),
+    /// A rendered image, one string per row, e.g. day10's CRT.
+    Grid(Vec<String>),
+}
+
+impl Answer {
+    /// A grid from text with one row per line.
+    pub fn grid(text: &str) -> Answer {
+        Answer::Grid(text.lines().map(str::to_owned).collect())
+    }
+
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Answer::Grid(rows) if rows.len() > 1)
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{n}"),
+            Answer::Text(text) => write!(f, "{text}"),
+            Answer::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+macro_rules! from_int {
+    ($($ty:ty),*) => {
+        $(impl From<$ty> for Answer {
+            fn from(n: $ty) -> Answer {
+                Answer::Int(n.into())
+            }
+        })*
+    };
+}
+
+from_int!(i32, i64, u32, u64);
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Answer {
+        Answer::Int(n as i128)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Answer {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Answer {
+        Answer::Text(text.to_owned())
+    }
+}
+
+#[test]
+fn test() {
+    assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+    assert_eq!(Answer::from(-3i32), Answer::Int(-3));
+    assert_eq!(Answer::from("CMZ").to_string(), "CMZ");
+
+    let screen = Answer::grid("#..\n.#.\n");
+    assert_eq!(screen, Answer::Grid(vec!["#..".to_owned(), ".#.".to_owned()]));
+    assert_eq!(screen.to_string(), "#..\n.#.");
+    assert!(screen.is_multiline());
+    assert!(!Answer::Int(1).is_multiline());
+}
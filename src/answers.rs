@@ -0,0 +1,243 @@
+use std::{
+    collections::BTreeMap,
+    fmt, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{error::Result, input::InputDir, Answer, Day};
+
+const HEADER: &str = "# year\tday\tpart\tinput hash\tanswer";
+
+/// Lines without a year were written before there was more than one.
+const LEGACY_YEAR: u32 = 2022;
+
+/// Known answers, keyed by year, day, part and a hash of the input they
+/// belong to, so one file can hold the answers for every teammate's input.
+#[derive(Debug, Default)]
+pub struct Answers {
+    entries: BTreeMap<(u32, u32, u32, u64), String>,
+}
+
+impl Answers {
+    /// Reads an answers file; a missing file is treated as empty.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Answers> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => text.parse().map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        std::fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, year: u32, day: u32, part: u32, input: &str) -> Option<&str> {
+        self.entries
+            .get(&(year, day, part, input_hash(input)))
+            .map(String::as_str)
+    }
+
+    pub fn insert(&mut self, year: u32, day: u32, part: u32, input: &str, answer: &Answer) {
+        self.entries
+            .insert((year, day, part, input_hash(input)), answer.to_string());
+    }
+
+    /// Answers are compared by their rendered text.
+    pub fn check(&self, year: u32, day: u32, part: u32, input: &str, answer: &Answer) -> Status {
+        self.check_text(year, day, part, input, &answer.to_string())
+    }
+
+    pub fn check_text(&self, year: u32, day: u32, part: u32, input: &str, answer: &str) -> Status {
+        match self.get(year, day, part, input) {
+            Some(expected) if expected == answer => Status::Pass,
+            Some(expected) => Status::Fail(expected.to_owned()),
+            None => Status::Unknown,
+        }
+    }
+}
+
+impl std::str::FromStr for Answers {
+    type Err = crate::ParseError;
+
+    fn from_str(text: &str) -> std::result::Result<Self, Self::Err> {
+        let mut answers = Answers::default();
+
+        for line in text.lines() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.splitn(5, '\t').collect();
+            let (year, [day, part, hash, answer]) = match fields[..] {
+                [year, day, part, hash, answer] => (crate::error::number(text, year)?, [day, part, hash, answer]),
+                [day, part, hash, answer] => (LEGACY_YEAR, [day, part, hash, answer]),
+                _ => {
+                    return Err(crate::ParseError::at(
+                        text,
+                        line,
+                        "expected year, day, part, input hash and answer separated by tabs",
+                    ))
+                }
+            };
+
+            let day = crate::error::number(text, day)?;
+            let part = crate::error::number(text, part)?;
+            let hash = u64::from_str_radix(hash, 16)
+                .map_err(|_| crate::ParseError::at(text, hash, "expected a hex input hash"))?;
+
+            answers.entries.insert((year, day, part, hash), unescape(answer));
+        }
+
+        Ok(answers)
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{HEADER}")?;
+        for ((year, day, part, hash), answer) in &self.entries {
+            writeln!(f, "{year}\t{day}\t{part}\t{hash:016x}\t{}", escape(answer))?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    /// Holds the expected answer.
+    Fail(String),
+    /// No answer was given, and none is recorded to expect.
+    Error,
+    Unknown,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail(expected) => write!(f, "FAIL (expected {})", escape(expected)),
+            Status::Error => write!(f, "ERROR"),
+            Status::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// The answers file that lives next to the inputs in `dir`.
+pub fn path(dir: &InputDir) -> PathBuf {
+    dir.dir().join("answers.txt")
+}
+
+/// Parses `input` and solves both parts, checking each answer against `answers`.
+/// A part that gives no answer fails, whether or not one is recorded.
+pub fn verify(day: &Day, input: &str, answers: &Answers) -> Result<Vec<(u32, Result<Answer>, Status)>> {
+    let parsed = day.parse(input)?;
+
+    Ok([1, 2]
+        .into_iter()
+        .map(|part| {
+            let answer = day.part(part, &parsed).unwrap();
+            let status = match &answer {
+                Ok(answer) => answers.check(day.year, day.day, part, input, answer),
+                Err(_) => match answers.get(day.year, day.day, part, input) {
+                    Some(expected) => Status::Fail(expected.to_owned()),
+                    None => Status::Error,
+                },
+            };
+            (part, answer, status)
+        })
+        .collect())
+}
+
+/// 64-bit FNV-1a. Stable across platforms and Rust versions, unlike the std
+/// hashers, so hashes written to the answers file stay valid.
+pub fn input_hash(input: &str) -> u64 {
+    input.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+pub(crate) fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+pub(crate) fn unescape(answer: &str) -> String {
+    let mut unescaped = String::new();
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                unescaped.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                unescaped.push('\\');
+                chars.next();
+            }
+            _ => unescaped.push(c),
+        }
+    }
+    unescaped
+}
+
+#[test]
+fn test() {
+    assert_eq!(input_hash(""), 0xcbf29ce484222325);
+    assert_eq!(input_hash("a"), 0xaf63dc4c8601ec8c);
+
+    let mut answers = Answers::default();
+    answers.insert(2022, 10, 2, "noop", &Answer::grid("#.\n.#\\"));
+    answers.insert(2022, 1, 1, "1000", &Answer::Int(1000));
+
+    let text = answers.to_string();
+    assert_eq!(
+        text,
+        format!("{HEADER}\n2022\t1\t1\t{:016x}\t1000\n2022\t10\t2\t{:016x}\t#.\\n.#\\\\\n", input_hash("1000"), input_hash("noop"))
+    );
+
+    let answers: Answers = text.parse().unwrap();
+    assert_eq!(answers.get(2022, 10, 2, "noop"), Some("#.\n.#\\"));
+    assert_eq!(answers.check(2022, 1, 1, "1000", &Answer::Int(1000)), Status::Pass);
+    assert_eq!(answers.check(2022, 1, 1, "1000", &Answer::Int(999)), Status::Fail("1000".to_owned()));
+    assert_eq!(answers.check(2022, 1, 2, "1000", &Answer::Int(1000)), Status::Unknown);
+    assert_eq!(answers.check(2022, 1, 1, "2000", &Answer::Int(1000)), Status::Unknown);
+    assert_eq!(answers.check(2023, 1, 1, "1000", &Answer::Int(1000)), Status::Unknown);
+
+    let legacy: Answers = format!("1\t1\t{:016x}\t1000\n", input_hash("1000")).parse().unwrap();
+    assert_eq!(legacy.get(2022, 1, 1, "1000"), Some("1000"));
+
+    assert!("1\t1\tzz\t1000".parse::<Answers>().is_err());
+    assert!("2022\t1".parse::<Answers>().is_err());
+
+    // Monkey 0 multiplies its first item past any worry level day 11 can hold.
+    let day11 = crate::day(2022, 11).unwrap();
+    let input = include_str!("../input/2022/examples/day11-1.txt").replace("old * 19", "old * 9223372036854775807");
+    let mut answers = Answers::default();
+    let results = verify(day11, &input, &answers).unwrap();
+    assert!(results[0].1.is_err());
+    assert_eq!(results[0].2, Status::Error);
+    answers.insert(2022, 11, 1, &input, &Answer::Int(10605));
+    assert_eq!(verify(day11, &input, &answers).unwrap()[0].2, Status::Fail("10605".to_owned()));
+}
+
+#[test]
+fn real() {
+    let dir = InputDir::from_env();
+    let answers = Answers::load(path(&dir)).unwrap();
+
+    for day in crate::DAYS {
+        let Some(input) = crate::input::for_test(day.year, day.day) else {
+            continue;
+        };
+
+        for (part, answer, status) in verify(day, &input, &answers).unwrap() {
+            assert!(
+                !matches!(status, Status::Fail(_) | Status::Error),
+                "{} day {:02} part {part}: got {answer:?}, {status}",
+                day.year,
+                day.day
+            );
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use std::{
+    fmt::{self, Write},
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::{error::Result, Day};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Timings {
+    /// Panics if `samples` is empty.
+    pub fn from_samples(samples: &mut [Duration]) -> Timings {
+        samples.sort();
+
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+
+        Timings {
+            min: samples[0],
+            median,
+            mean: samples.iter().sum::<Duration>() / samples.len() as u32,
+        }
+    }
+}
+
+/// Calls `f` `warmup` times untimed, then `runs` times timed.
+pub fn measure<T>(warmup: usize, runs: usize, mut f: impl FnMut() -> T) -> Timings {
+    for _ in 0..warmup {
+        black_box(f());
+    }
+
+    let mut samples: Vec<Duration> = (0..runs.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+
+    Timings::from_samples(&mut samples)
+}
+
+#[derive(Debug)]
+pub struct DayBench {
+    pub year: u32,
+    pub day: u32,
+    pub parse: Timings,
+    pub part1: Timings,
+    pub part2: Timings,
+}
+
+/// Times `day`'s parse and both parts separately. Each phase is run once
+/// before timing so a bad input fails fast instead of being benchmarked.
+pub fn bench_day(day: &Day, input: &str, warmup: usize, runs: usize) -> Result<DayBench> {
+    let parsed = day.parse(input)?;
+    day.part1(&parsed)?;
+    day.part2(&parsed)?;
+
+    Ok(DayBench {
+        year: day.year,
+        day: day.day,
+        parse: measure(warmup, runs, || day.parse(input)),
+        part1: measure(warmup, runs, || day.part1(&parsed)),
+        part2: measure(warmup, runs, || day.part2(&parsed)),
+    })
+}
+
+pub fn table(results: &[DayBench]) -> String {
+    let mut table = format!("{:<4} {:<4} {:<7} {:>10} {:>10} {:>10}\n", "Year", "Day", "Phase", "Min", "Median", "Mean");
+
+    for result in results {
+        for (phase, timings) in [("parse", result.parse), ("part 1", result.part1), ("part 2", result.part2)] {
+            writeln!(
+                table,
+                "{:<4} {:<4} {:<7} {:>10} {:>10} {:>10}",
+                result.year,
+                format!("{:02}", result.day),
+                phase,
+                Human(timings.min).to_string(),
+                Human(timings.median).to_string(),
+                Human(timings.mean).to_string(),
+            )
+            .unwrap();
+        }
+    }
+
+    let total: Duration = results
+        .iter()
+        .map(|r| r.parse.median + r.part1.median + r.part2.median)
+        .sum();
+    writeln!(table, "total of medians: {}", Human(total)).unwrap();
+
+    table
+}
+
+/// Formats a duration with three significant figures in a fitting unit.
+pub struct Human(pub Duration);
+
+impl fmt::Display for Human {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nanos = self.0.as_nanos() as f64;
+        let (value, unit) = match nanos {
+            n if n < 1e3 => (n, "ns"),
+            n if n < 1e6 => (n / 1e3, "µs"),
+            n if n < 1e9 => (n / 1e6, "ms"),
+            n => (n / 1e9, "s"),
+        };
+        let decimals = match value {
+            v if v < 10.0 => 2,
+            v if v < 100.0 => 1,
+            _ => 0,
+        };
+
+        write!(f, "{value:.decimals$}{unit}")
+    }
+}
+
+#[test]
+fn test() {
+    let ms = Duration::from_millis;
+
+    let timings = Timings::from_samples(&mut [ms(5), ms(1), ms(3)]);
+    assert_eq!(timings, Timings { min: ms(1), median: ms(3), mean: ms(3) });
+    assert_eq!(Timings::from_samples(&mut [ms(4), ms(1), ms(2), ms(9)]).median, ms(3));
+
+    let mut calls = 0;
+    measure(2, 5, || calls += 1);
+    assert_eq!(calls, 7);
+
+    assert_eq!(Human(Duration::from_nanos(950)).to_string(), "950ns");
+    assert_eq!(Human(Duration::from_micros(1234)).to_string(), "1.23ms");
+    assert_eq!(Human(Duration::from_millis(45678)).to_string(), "45.7s");
+
+    let result = bench_day(crate::day(2022, 2).unwrap(), "A Y\nB X\nC Z", 0, 3).unwrap();
+    assert_eq!((result.year, result.day), (2022, 2));
+    let table = table(&[result]);
+    assert!(table.starts_with("Year Day  Phase"));
+    assert!(table.lines().nth(1).unwrap().starts_with("2022 02   parse"));
+    assert!(bench_day(crate::day(2022, 2).unwrap(), "A Q", 0, 3).is_err());
+}
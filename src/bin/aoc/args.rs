@@ -0,0 +1,129 @@
+use std::sync::OnceLock;
+
+use aoc2022::{input::InputDir, Day};
+
+pub const YEAR_VAR: &str = "AOC_YEAR";
+
+static YEAR: OnceLock<u32> = OnceLock::new();
+
+/// Command line arguments after the command name, split into positional
+/// arguments, flags taking a value and switches.
+pub struct Args {
+    pub positional: Vec<String>,
+    values: Vec<(&'static str, String)>,
+    switches: Vec<&'static str>,
+}
+
+impl Args {
+    pub fn parse(
+        args: &[String],
+        value_flags: &[&'static str],
+        switch_flags: &[&'static str],
+    ) -> Result<Args, String> {
+        let mut parsed = Args {
+            positional: vec![],
+            values: vec![],
+            switches: vec![],
+        };
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            let name = if arg == "-i" { "--input" } else { arg.as_str() };
+
+            if let Some(&flag) = value_flags.iter().find(|&&flag| flag == name) {
+                let value = args.next().ok_or(format!("`{arg}` needs a value"))?;
+                parsed.values.push((flag, value.to_owned()));
+            } else if let Some(&flag) = switch_flags.iter().find(|&&flag| flag == name) {
+                parsed.switches.push(flag);
+            } else if name.starts_with("--") {
+                return Err(format!("unknown option `{arg}`"));
+            } else {
+                parsed.positional.push(arg.to_owned());
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    pub fn value(&self, flag: &str) -> Option<&str> {
+        self.values
+            .iter()
+            .rev()
+            .find(|(name, _)| *name == flag)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn switch(&self, flag: &str) -> bool {
+        self.switches.contains(&flag)
+    }
+
+    pub fn expect_positional(&self, max: usize) -> Result<(), String> {
+        match self.positional.get(max) {
+            Some(extra) => Err(format!("unexpected argument `{extra}`")),
+            None => Ok(()),
+        }
+    }
+
+    /// The input directory from `--input-dir`, falling back to the environment.
+    pub fn input_dir(&self) -> InputDir {
+        match self.value("--input-dir") {
+            Some(dir) => InputDir::new(dir),
+            None => InputDir::from_env(),
+        }
+    }
+}
+
+/// Sets the year commands work on, from `--year`.
+pub fn set_year(year: &str) -> Result<(), String> {
+    let year = parse_year(year)?;
+    YEAR.set(year).map_err(|_| "`--year` given twice".to_owned())
+}
+
+/// The year from `--year`, then `$AOC_YEAR`, then the latest year with
+/// solved days.
+pub fn year() -> Result<u32, String> {
+    if let Some(&year) = YEAR.get() {
+        return Ok(year);
+    }
+    match std::env::var(YEAR_VAR) {
+        Ok(year) => parse_year(&year).map_err(|err| format!("{YEAR_VAR}: {err}")),
+        Err(_) => Ok(*aoc2022::years().last().expect("some year has solved days")),
+    }
+}
+
+fn parse_year(year: &str) -> Result<u32, String> {
+    year.parse().map_err(|_| format!("invalid year `{year}`"))
+}
+
+/// Every registered day of the current year.
+pub fn all_days() -> Result<Vec<&'static Day>, String> {
+    let year = year()?;
+
+    match aoc2022::days(year).collect::<Vec<_>>() {
+        days if days.is_empty() => Err(format!("no days of {year} are implemented")),
+        days => Ok(days),
+    }
+}
+
+/// A day number, or `all` for every registered day, of the current year.
+pub fn days(arg: Option<&String>) -> Result<Vec<&'static Day>, String> {
+    let year = year()?;
+
+    match arg.map(String::as_str) {
+        Some("all") => all_days(),
+        Some(day) => {
+            let number = day.parse().map_err(|_| format!("invalid day `{day}`"))?;
+            Ok(vec![aoc2022::day(year, number).ok_or(format!("{year} day {number} is not implemented"))?])
+        }
+        None => Err("missing day".to_owned()),
+    }
+}
+
+pub fn part(arg: Option<&String>) -> Result<Option<u32>, String> {
+    match arg.map(String::as_str) {
+        Some("1") => Ok(Some(1)),
+        Some("2") => Ok(Some(2)),
+        Some(part) => Err(format!("invalid part `{part}`")),
+        None => Ok(None),
+    }
+}
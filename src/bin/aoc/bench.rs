@@ -0,0 +1,50 @@
+use std::process::ExitCode;
+
+use aoc2022::bench;
+
+use crate::args::{self, Args};
+
+pub fn main(args: &[String]) -> Result<ExitCode, String> {
+    let args = Args::parse(args, &["--input-dir", "--runs", "--warmup"], &[])?;
+    args.expect_positional(1)?;
+
+    let days = match args.positional.first() {
+        Some(_) => args::days(args.positional.first())?,
+        None => args::all_days()?,
+    };
+    let runs = count(&args, "--runs", 10)?;
+    let warmup = count(&args, "--warmup", 1)?;
+    let dir = args.input_dir();
+
+    let mut code = ExitCode::SUCCESS;
+    let mut results = vec![];
+
+    for day in days {
+        let result = dir
+            .load(day.year, day.day)
+            .map_err(aoc2022::Error::from)
+            .and_then(|input| bench::bench_day(day, &input, warmup, runs));
+
+        match result {
+            Ok(result) => results.push(result),
+            Err(err) => {
+                eprintln!("{} day {:02}: {err}", day.year, day.day);
+                code = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    if cfg!(debug_assertions) {
+        eprintln!("note: this is a debug build, use `cargo run --release` for meaningful timings");
+    }
+    print!("{}", bench::table(&results));
+
+    Ok(code)
+}
+
+fn count(args: &Args, flag: &str, default: usize) -> Result<usize, String> {
+    match args.value(flag) {
+        Some(value) => value.parse().map_err(|_| format!("invalid `{flag}` value `{value}`")),
+        None => Ok(default),
+    }
+}
@@ -0,0 +1,66 @@
+use std::process::ExitCode;
+
+use aoc2022::{crosscheck, examples, input::InputError};
+
+use crate::args::{self, Args};
+
+pub fn main(args: &[String]) -> Result<ExitCode, String> {
+    let args = Args::parse(args, &["--input-dir"], &[])?;
+    args.expect_positional(1)?;
+    let year = args::year()?;
+
+    let days = match args.positional.first().map(String::as_str) {
+        Some("all") => crosscheck::candidates(aoc2022::DAYS).into_iter().filter(|day| day.year == year).collect(),
+        _ => args::days(args.positional.first())?,
+    };
+    let dir = args.input_dir();
+    let examples_dir = examples::dir(year);
+    let examples = examples::discover(&examples_dir).map_err(|err| format!("{}: {err}", examples_dir.display()))?;
+
+    let mut mismatches = 0;
+    let mut errors = 0;
+
+    for day in days {
+        println!("{} day {:02}: {}", day.year, day.day, day.title);
+
+        let mut sources = vec![];
+        match dir.load(day.year, day.day) {
+            Ok(input) => sources.push(("input".to_owned(), input)),
+            Err(InputError::Missing(_) | InputError::Locked(_)) => {}
+            Err(err) => {
+                eprintln!("  {err}");
+                errors += 1;
+            }
+        }
+        sources.extend(
+            examples
+                .iter()
+                .filter(|example| example.day == day.day)
+                .map(|example| (format!("example {}", example.name), example.input.clone())),
+        );
+
+        for (source, input) in sources {
+            let comparisons = match crosscheck::compare(day, &input) {
+                Ok(comparisons) => comparisons,
+                Err(err) => {
+                    eprintln!("  {source}: {err}");
+                    errors += 1;
+                    continue;
+                }
+            };
+
+            for comparison in comparisons {
+                if !comparison.agrees() {
+                    mismatches += 1;
+                }
+                println!("  {source} {}", comparison.to_string().replace('\n', "\n  "));
+            }
+        }
+    }
+
+    if mismatches > 0 {
+        eprintln!("error: implementations disagree on {mismatches} parts");
+    }
+
+    Ok(if mismatches + errors == 0 { ExitCode::SUCCESS } else { ExitCode::FAILURE })
+}
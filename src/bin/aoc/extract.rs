@@ -0,0 +1,40 @@
+use std::{path::PathBuf, process::ExitCode};
+
+use aoc2022::puzzle::Puzzle;
+
+use crate::args::{self, Args};
+
+pub fn main(args: &[String]) -> Result<ExitCode, String> {
+    let args = Args::parse(args, &["--root"], &["--force"])?;
+    args.expect_positional(1)?;
+
+    let path = args.positional.first().ok_or("missing puzzle page")?;
+    let html = std::fs::read_to_string(path).map_err(|err| format!("{path}: {err}"))?;
+    let puzzle: Puzzle = html.parse().map_err(|err| format!("{path}: {err}"))?;
+
+    let year = match puzzle.year {
+        Some(year) => year,
+        None => args::year()?,
+    };
+    let root = args
+        .value("--root")
+        .map_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")), PathBuf::from);
+    let input = root.join(format!("input/{year}"));
+
+    let written = puzzle
+        .write(&input.join("puzzles"), &input.join("examples"), args.switch("--force"))
+        .map_err(|err| err.to_string())?;
+
+    println!("{year} day {:02}: {}", puzzle.day, puzzle.title);
+    for path in written {
+        println!("wrote {}", path.display());
+    }
+    if puzzle.example.is_none() {
+        println!("found no example, add one to the examples directory by hand");
+    }
+    if aoc2022::day(year, puzzle.day).is_none() {
+        println!("run `aoc --year {year} new {} --title {:?}` to add the module", puzzle.day, puzzle.title);
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
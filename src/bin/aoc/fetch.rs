@@ -0,0 +1,47 @@
+use std::{process::ExitCode, time::SystemTime};
+
+use aoc2022::{
+    config::Config,
+    fetch::{self, Client, FetchError, Fetched},
+};
+
+use crate::args::{self, Args};
+
+pub fn main(args: &[String]) -> Result<ExitCode, String> {
+    let args = Args::parse(args, &["--input-dir"], &["--force"])?;
+    args.expect_positional(1)?;
+
+    let year = args::year()?;
+    // Inputs are wanted before a day is solved, so any day can be fetched,
+    // not just registered ones.
+    let days: Vec<u32> = match args.positional.first().map(String::as_str) {
+        Some("all") => (1..=25).filter(|&day| fetch::unlocks_at(year, day) <= SystemTime::now()).collect(),
+        Some(day) => match day.parse() {
+            Ok(day @ 1..=25) => vec![day],
+            _ => return Err(format!("invalid day `{day}`")),
+        },
+        None => return Err("missing day".to_owned()),
+    };
+
+    let config = Config::from_env().map_err(|err| err.to_string())?;
+    let client = Client::new(config, args.input_dir());
+    let mut code = ExitCode::SUCCESS;
+
+    for day in days {
+        match client.input(year, day, args.switch("--force")) {
+            Ok(Fetched::Cached(path)) => println!("{year} day {day:02}: already have {}", path.display()),
+            Ok(Fetched::Downloaded(path)) => println!("{year} day {day:02}: saved {}", path.display()),
+            // No other day would fare better.
+            Err(err @ (FetchError::NoSession | FetchError::BadSession)) => {
+                eprintln!("error: {err}");
+                return Ok(ExitCode::FAILURE);
+            }
+            Err(err) => {
+                eprintln!("{year} day {day:02}: {err}");
+                code = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    Ok(code)
+}
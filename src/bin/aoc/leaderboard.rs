@@ -0,0 +1,19 @@
+use std::process::ExitCode;
+
+use aoc2022::leaderboard::Leaderboard;
+
+use crate::args::Args;
+
+pub fn main(args: &[String]) -> Result<ExitCode, String> {
+    let args = Args::parse(args, &[], &[])?;
+    args.expect_positional(1)?;
+
+    let path = args.positional.first().ok_or("missing leaderboard file")?;
+    let text = std::fs::read_to_string(path).map_err(|err| format!("{path}: {err}"))?;
+    let board: Leaderboard = text.parse().map_err(|err| format!("{path}: {err}"))?;
+
+    let solved: Vec<(u32, &str)> = aoc2022::days(board.year).map(|day| (day.day, day.title)).collect();
+    print!("{}", board.report(&solved));
+
+    Ok(ExitCode::SUCCESS)
+}
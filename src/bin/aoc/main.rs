@@ -0,0 +1,166 @@
+use std::process::ExitCode;
+
+mod args;
+mod bench;
+mod crosscheck;
+mod extract;
+mod fetch;
+mod leaderboard;
+mod new;
+mod run;
+mod submit;
+mod vault;
+mod verify;
+mod watch;
+
+#[global_allocator]
+static ALLOCATOR: aoc2022::memory::Counting = aoc2022::memory::Counting;
+
+const USAGE: &str = "usage: aoc <command> [arguments]
+
+  run <day|all> [part] [--input <file>|-] [--input-dir <dir>] [--format <format>]
+      [--jobs <n>|auto] [--no-cache] [--memory] [--stream]
+      solve a day, or every day, and print the answers; with --format json
+      or csv, print a report of answers, timings and verification status;
+      with --jobs, parse and solve on <n> worker threads and print the
+      total wall and CPU time. Answers are cached in cache.txt in the input
+      directory by input hash and solver version; --no-cache ignores it.
+      --memory reports allocations, bytes allocated and peak heap for the
+      parse and each part, and solves everything again instead of using
+      the cache. --stream solves a single day while reading its input, for
+      inputs too large to load; days 1, 2, 3, 4, 6, 9 and 10 support it
+  bench [day|all] [--runs <n>] [--warmup <n>] [--input-dir <dir>]
+      time parsing and each part, reporting min, median and mean
+      over <n> runs (default 10) after warm-up runs (default 1)
+  verify <day|all> [--input-dir <dir>] [--record]
+      check answers against answers.txt in the input directory;
+      --record saves answers that are not known yet
+  crosscheck <day|all> [--input-dir <dir>]
+      solve the input and every example with each implementation of each
+      part and fail if their answers differ; `all` checks the days that
+      have more than one implementation
+  watch <day> [--input-dir <dir>] [--interval <ms>]
+      re-run a day whenever its input or examples change, marking each
+      answer as unchanged (=), changed (~), new (+) or gone (-)
+  fetch <day|all> [--input-dir <dir>] [--force]
+      download a day's input, or every unlocked day's, to where run reads
+      it from; inputs already there are kept unless --force. Needs the
+      session cookie of a logged in browser as `session = <token>` in the
+      config file ($AOC_CONFIG, then ~/.config/aoc/config) or $AOC_SESSION.
+      Requests are at least `interval` seconds apart, 5 by default
+  submit <day> <part> [--input-dir <dir>] [--answer <answer>]
+      solve a part and send the answer, or <answer> instead, to the site,
+      printing whether it was right. Every verdict is kept in
+      submissions.txt in the input directory, and answers already known
+      to be wrong, or past an earlier too high or too low, are not sent
+      again; right answers are also saved to answers.txt. Uses the same
+      session and interval as fetch
+  extract <page.html> [--root <dir>] [--force]
+      read a puzzle page saved from the browser and write its description
+      as Markdown to input/YYYY/puzzles/dayNN.md, and its first example and
+      the example answers to input/YYYY/examples/dayNN-1.txt and .answers.
+      Examples that already hold something else are kept unless --force;
+      <dir> is the crate root, this checkout by default
+  leaderboard <file>
+      read a private leaderboard's JSON export and print the members
+      ranked by local score, then for each day how long everyone took
+      after it unlocked and the time from part 1 to part 2, naming the
+      days this crate solves
+  vault add <day|all> [--input-dir <dir>] [--remove]
+  vault export <day|all> [--input-dir <dir>] [--force]
+  vault list [--input-dir <dir>]
+      keep inputs encrypted in vault/YYYY/dayNN.sealed in the input directory,
+      with a key made from the passphrase in $AOC_VAULT_KEY and the random
+      salt in vault/salt. Every command reads a day from the vault when its
+      plain input is missing. add encrypts a day's input, or every plain
+      input of the year, and with --remove deletes the plain file once the
+      encrypted copy is known to open; export writes plain inputs back,
+      replacing different ones only with --force; list shows every year's
+      entries and whether they open with the key. The passphrase is not
+      in the repository: ask whoever owns the inputs for it, and set it as
+      the AOC_VAULT_KEY secret in CI so the real tests can run there
+  new <day> [--title <title>] [--root <dir>]
+      add src/yYYYY/dayNN.rs, register it in its year's mod.rs and in
+      lib.rs and create empty example, example answers and input files;
+      <dir> is the crate root, this checkout by default
+
+  day          puzzle day, or `all` for every registered day of the year
+  part         1 or 2, runs both parts when omitted
+  --input      read the input from <file>, or from stdin when `-`
+  --input-dir  read YYYY/dayNN.txt from <dir>; defaults to $AOC_INPUT_DIR,
+               then input/
+  --year       puzzle year; defaults to $AOC_YEAR, then the latest year with
+               solved days
+  --log        print solver diagnostics to stderr, e.g. `debug`, `9=trace` or
+               `info,2022/12=debug`, where a day without a year means that
+               day of every year; defaults to $AOC_LOG, off when unset
+  --timeout    stop any part still running after <seconds>, reporting it as
+               timed out; `off` for no limit; defaults to $AOC_TIMEOUT, then 60.
+               Only solvers that poll for cancellation can be stopped";
+
+fn main() -> ExitCode {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+
+    let result = match take_global_options(&mut args) {
+        Err(message) => Err(message),
+        Ok(()) => dispatch(&args),
+    };
+
+    match result {
+        Ok(code) => code,
+        Err(message) => {
+            eprintln!("error: {message}\n\n{USAGE}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn dispatch(args: &[String]) -> Result<ExitCode, String> {
+    match args.first().map(String::as_str) {
+        Some("run") => run::main(&args[1..]),
+        Some("verify") => verify::main(&args[1..]),
+        Some("bench") => bench::main(&args[1..]),
+        Some("new") => new::main(&args[1..]),
+        Some("crosscheck") => crosscheck::main(&args[1..]),
+        Some("fetch") => fetch::main(&args[1..]),
+        Some("submit") => submit::main(&args[1..]),
+        Some("leaderboard") => leaderboard::main(&args[1..]),
+        Some("extract") => extract::main(&args[1..]),
+        Some("vault") => vault::main(&args[1..]),
+        Some("watch") => watch::main(&args[1..]),
+        Some("help" | "-h" | "--help") => {
+            println!("{USAGE}");
+            Ok(ExitCode::SUCCESS)
+        }
+        Some(other) => Err(format!("unknown command `{other}`")),
+        None => Err("missing command".to_owned()),
+    }
+}
+
+/// Removes `--log <spec>`, `--timeout <seconds>` and `--year <year>`, which
+/// any command accepts, and applies them.
+fn take_global_options(args: &mut Vec<String>) -> Result<(), String> {
+    if let Some(spec) = take_value(args, "--log")? {
+        aoc2022::log::configure(&spec)?;
+    }
+    if let Some(seconds) = take_value(args, "--timeout")? {
+        aoc2022::cancel::configure(aoc2022::cancel::parse_budget(&seconds)?);
+    }
+    if let Some(year) = take_value(args, "--year")? {
+        args::set_year(&year)?;
+    }
+    Ok(())
+}
+
+fn take_value(args: &mut Vec<String>, flag: &str) -> Result<Option<String>, String> {
+    let Some(i) = args.iter().position(|arg| arg == flag) else {
+        return Ok(None);
+    };
+    if i + 1 == args.len() {
+        return Err(format!("`{flag}` needs a value"));
+    }
+
+    let value = args.remove(i + 1);
+    args.remove(i);
+    Ok(Some(value))
+}
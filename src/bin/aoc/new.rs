@@ -0,0 +1,29 @@
+use std::{path::PathBuf, process::ExitCode};
+
+use aoc2022::scaffold;
+
+use crate::args::{self, Args};
+
+pub fn main(args: &[String]) -> Result<ExitCode, String> {
+    let args = Args::parse(args, &["--title", "--root"], &[])?;
+    args.expect_positional(1)?;
+
+    let day = match args.positional.first() {
+        Some(day) => day.parse().map_err(|_| format!("invalid day `{day}`"))?,
+        None => return Err("missing day".to_owned()),
+    };
+    let year = args::year()?;
+    let title = args.value("--title").map_or_else(|| format!("Day {day}"), str::to_owned);
+    let root = args
+        .value("--root")
+        .map_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")), PathBuf::from);
+
+    let written = scaffold::create(&root, year, day, &title).map_err(|err| err.to_string())?;
+
+    for path in written {
+        println!("wrote {}", path.display());
+    }
+    println!("paste the example into the examples file and your input into {year}/day{day:02}.txt");
+
+    Ok(ExitCode::SUCCESS)
+}
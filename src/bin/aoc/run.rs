@@ -0,0 +1,315 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader},
+    path::PathBuf,
+    process::ExitCode,
+    thread,
+    time::Duration,
+};
+
+use aoc2022::{
+    answers::{self, Answers},
+    bench::Human,
+    cache::{self, Cache},
+    input::{self, InputDir, InputError},
+    memory::{self, Usage},
+    parallel,
+    report::{self, Record},
+    stream, Day,
+};
+
+use crate::args::{self, Args};
+
+enum Source {
+    Dir(InputDir),
+    File(String),
+    Stdin,
+}
+
+fn read_input(day: &Day, source: &Source) -> Result<String, InputError> {
+    match source {
+        Source::Dir(dir) => dir.load(day.year, day.day),
+        Source::File(path) => input::read_file(path),
+        Source::Stdin => input::read_stdin(),
+    }
+}
+
+pub fn main(args: &[String]) -> Result<ExitCode, String> {
+    let args = Args::parse(args, &["--input", "--input-dir", "--format", "--jobs"], &["--no-cache", "--memory", "--stream"])?;
+    args.expect_positional(2)?;
+
+    let days = args::days(args.positional.first())?;
+    let only_part = args::part(args.positional.get(1))?;
+
+    let source = match args.value("--input") {
+        Some("-") => Source::Stdin,
+        Some(path) => Source::File(path.to_owned()),
+        None => Source::Dir(args.input_dir()),
+    };
+
+    if days.len() > 1 && !matches!(source, Source::Dir(_)) {
+        return Err("`--input` can only be used with a single day".to_owned());
+    }
+
+    let format = match args.value("--format") {
+        None => "text",
+        Some(format @ ("text" | "json" | "csv")) => format,
+        Some(other) => return Err(format!("unknown format `{other}`, expected text, json or csv")),
+    };
+    let jobs = match args.value("--jobs") {
+        None => None,
+        Some("auto") => Some(thread::available_parallelism().map_or(1, |n| n.get())),
+        Some(jobs) => match jobs.parse() {
+            Ok(jobs) if jobs > 0 => Some(jobs),
+            _ => return Err(format!("invalid `--jobs` value `{jobs}`")),
+        },
+    };
+
+    if args.switch("--memory") && !memory::enable() {
+        return Err("`--memory` needs the counting allocator, which this build does not use".to_owned());
+    }
+
+    let parts = only_part.map_or(vec![1, 2], |part| vec![part]);
+
+    if args.switch("--stream") {
+        if format != "text" || jobs.is_some() {
+            return Err("`--stream` only prints text and runs on one thread".to_owned());
+        }
+        let [day] = days[..] else {
+            return Err("`--stream` takes a single day".to_owned());
+        };
+        return streamed(day, &parts, &source);
+    }
+
+    let cache_path = cache::path(&args.input_dir());
+    let mut cache = if args.switch("--no-cache") || args.switch("--memory") {
+        Cache::default()
+    } else {
+        Cache::load(&cache_path).map_err(|err| format!("{}: {err}", cache_path.display()))?
+    };
+
+    let code = if format != "text" || jobs.is_some() {
+        pooled(&days, &parts, &source, &args.input_dir(), &mut cache, format, jobs.unwrap_or(1))?
+    } else {
+        sequential(&days, &parts, &source, &mut cache)
+    };
+
+    if !args.switch("--no-cache") && !args.switch("--memory") {
+        cache.save(&cache_path).map_err(|err| format!("{}: {err}", cache_path.display()))?;
+    }
+
+    Ok(code)
+}
+
+fn sequential(days: &[&Day], parts: &[u32], source: &Source, cache: &mut Cache) -> ExitCode {
+    let mut code = ExitCode::SUCCESS;
+
+    for day in days {
+        let input = read_input(day, source);
+
+        if let Some(answers) = input.as_ref().ok().and_then(|input| cache.get_all(day, parts, input)) {
+            println!("{} day {:02}: {} (cached)", day.year, day.day, day.title);
+            for (part, answer) in parts.iter().zip(answers) {
+                print_answer(*part, &answer);
+            }
+            continue;
+        }
+
+        println!("{} day {:02}: {}", day.year, day.day, day.title);
+
+        let input = match input {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("  {err}");
+                code = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        let (parsed, parse_memory) = memory::measure(|| day.parse(&input));
+        print_memory("parse", parse_memory);
+
+        let parsed = match parsed {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                eprintln!("  {err}");
+                code = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        for &part in parts {
+            let (answer, solve_memory) = memory::measure(|| day.part(part, &parsed).unwrap());
+            print_memory(&format!("part {part}"), solve_memory);
+
+            match answer {
+                Ok(answer) => {
+                    let answer = answer.to_string();
+                    print_answer(part, &answer);
+                    cache.insert(day, part, &input, &answer);
+                }
+                Err(err) => {
+                    eprintln!("  Part {part}: {err}");
+                    code = ExitCode::FAILURE;
+                }
+            }
+        }
+    }
+
+    code
+}
+
+/// Solves `day` straight from the input file or stdin without reading it
+/// into memory first. Nothing is cached, since that needs the whole input.
+fn streamed(day: &Day, parts: &[u32], source: &Source) -> Result<ExitCode, String> {
+    let stream = stream::solver(day.year, day.day).ok_or(format!("{} day {} cannot be streamed", day.year, day.day))?;
+
+    let path = match source {
+        Source::Dir(dir) => dir.path(day.year, day.day),
+        Source::File(path) => PathBuf::from(path),
+        Source::Stdin => PathBuf::from("-"),
+    };
+    let mut reader: Box<dyn BufRead> = match source {
+        Source::Stdin => Box::new(io::stdin().lock()),
+        // The vault is decrypted whole, so only plain inputs are streamed.
+        Source::Dir(dir) if !path.exists() => {
+            Box::new(io::Cursor::new(dir.load(day.year, day.day).map_err(|err| err.to_string())?))
+        }
+        _ => Box::new(BufReader::new(File::open(&path).map_err(|err| format!("{}: {err}", path.display()))?)),
+    };
+
+    println!("{} day {:02}: {}", day.year, day.day, day.title);
+
+    let (answers, usage) = memory::measure(|| stream(&mut reader));
+    print_memory("stream", usage);
+
+    let answers = match answers {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("  {err}");
+            return Ok(ExitCode::FAILURE);
+        }
+    };
+
+    let mut code = ExitCode::SUCCESS;
+    for (part, answer) in [1, 2].into_iter().zip(answers) {
+        if !parts.contains(&part) {
+            continue;
+        }
+        match answer {
+            Ok(answer) => print_answer(part, &answer.to_string()),
+            Err(err) => {
+                eprintln!("  Part {part}: {err}");
+                code = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    Ok(code)
+}
+
+fn print_answer(part: u32, answer: &str) {
+    if answer.contains('\n') {
+        println!("  Part {part}:\n{answer}");
+    } else {
+        println!("  Part {part}: {answer}");
+    }
+}
+
+/// Reports heap use on stderr, next to errors, so stdout stays just answers.
+fn print_memory(step: &str, usage: Option<Usage>) {
+    if let Some(usage) = usage {
+        eprintln!("  memory for {step}: {usage}");
+    }
+}
+
+/// Runs `days` on a pool of `jobs` workers and prints the answers as text,
+/// or one JSON or CSV record per part with timings and the status against
+/// the answers file in `dir`. Output is in day order whatever the schedule.
+fn pooled(
+    days: &[&Day],
+    parts: &[u32],
+    source: &Source,
+    dir: &InputDir,
+    cache: &mut Cache,
+    format: &str,
+    jobs: usize,
+) -> Result<ExitCode, String> {
+    let path = answers::path(dir);
+    let answers = Answers::load(&path).map_err(|err| format!("{}: {err}", path.display()))?;
+
+    let inputs: Vec<Result<String, InputError>> = days.iter().map(|day| read_input(day, source)).collect();
+    let cached: Vec<Option<Vec<String>>> = days
+        .iter()
+        .zip(&inputs)
+        .map(|(day, input)| cache.get_all(day, parts, input.as_ref().ok()?))
+        .collect();
+    let work: Vec<(&Day, &str)> = days
+        .iter()
+        .zip(&inputs)
+        .zip(&cached)
+        .filter(|(_, cached)| cached.is_none())
+        .filter_map(|((&day, input), _)| Some((day, input.as_deref().ok()?)))
+        .collect();
+
+    let (solved, timing) = parallel::run(&work, parts, &answers, jobs);
+
+    let mut solved = solved.into_iter();
+    let mut records = vec![];
+    for ((day, input), cached) in days.iter().zip(&inputs).zip(cached) {
+        match (input, cached) {
+            (Ok(input), Some(cached)) => records.extend(parts.iter().zip(cached).map(|(&part, answer)| {
+                let status = answers.check_text(day.year, day.day, part, input, &answer);
+                Record::cached(day.year, day.day, part, answer, status)
+            })),
+            (Ok(input), None) => {
+                for record in solved.by_ref().take(parts.len()) {
+                    if let Ok(answer) = &record.answer {
+                        cache.insert(day, record.part, input, answer);
+                    }
+                    records.push(record);
+                }
+            }
+            (Err(err), _) => records.extend(parts.iter().map(|&part| Record::failed(day.year, day.day, part, Duration::ZERO, err))),
+        }
+    }
+
+    match format {
+        "json" => print!("{}", report::json(&records)),
+        "csv" => print!("{}", report::csv(&records)),
+        _ => {
+            for day in days {
+                let records: Vec<&Record> = records.iter().filter(|record| (record.year, record.day) == (day.year, day.day)).collect();
+                let cached = if records.iter().all(|record| record.cached) { " (cached)" } else { "" };
+                println!("{} day {:02}: {}{cached}", day.year, day.day, day.title);
+
+                if let Some(record) = records.first() {
+                    print_memory("parse", record.parse_memory);
+                }
+                for record in records {
+                    print_memory(&format!("part {}", record.part), record.solve_memory);
+                    match &record.answer {
+                        Ok(answer) => print_answer(record.part, answer),
+                        Err(err) => eprintln!("  Part {}: {err}", record.part),
+                    }
+                }
+            }
+        }
+    }
+
+    eprintln!(
+        "{} parts of {} days ({} cached) in {} wall, {} cpu on {} threads",
+        records.len(),
+        days.len(),
+        records.iter().filter(|record| record.cached).count(),
+        Human(timing.wall),
+        Human(timing.cpu),
+        timing.threads
+    );
+
+    Ok(if records.iter().all(|record| record.answer.is_ok()) {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
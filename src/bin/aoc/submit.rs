@@ -0,0 +1,75 @@
+use std::process::ExitCode;
+
+use aoc2022::{
+    answers::{self, Answers},
+    config::Config,
+    fetch::Client,
+    submit::{self, History, Verdict},
+};
+
+use crate::args::{self, Args};
+
+pub fn main(args: &[String]) -> Result<ExitCode, String> {
+    let args = Args::parse(args, &["--input-dir", "--answer"], &[])?;
+    args.expect_positional(2)?;
+
+    let day = match args::days(args.positional.first())?[..] {
+        [day] => day,
+        _ => return Err("submit takes a single day".to_owned()),
+    };
+    let part = args::part(args.positional.get(1))?.ok_or("missing part")?;
+    let dir = args.input_dir();
+    let input = dir.load(day.year, day.day).map_err(|err| err.to_string())?;
+
+    let answer = match args.value("--answer") {
+        Some(answer) => answer.to_owned(),
+        None => match day.parse(&input).and_then(|parsed| day.part(part, &parsed).expect("part is 1 or 2")) {
+            Ok(answer) => answer.to_string(),
+            Err(err) => {
+                eprintln!("error: {err}");
+                return Ok(ExitCode::FAILURE);
+            }
+        },
+    };
+    if answer.contains('\n') {
+        println!("{answer}");
+        eprintln!("error: the answer spans several lines, read it off and pass it with `--answer`");
+        return Ok(ExitCode::FAILURE);
+    }
+
+    let history_path = submit::path(&dir);
+    let mut history = History::load(&history_path).map_err(|err| format!("{}: {err}", history_path.display()))?;
+
+    print!("{} day {:02} part {part}: {answer} ", day.year, day.day);
+    if let Err(refusal) = history.check(day.year, day.day, part, &input, &answer) {
+        println!("not submitted, {refusal}");
+        return Ok(ExitCode::FAILURE);
+    }
+
+    let client = Client::new(Config::from_env().map_err(|err| err.to_string())?, dir.clone());
+    let verdict = match submit::submit(&client, day.year, day.day, part, &answer) {
+        Ok(verdict) => verdict,
+        Err(err) => {
+            println!("not submitted, {err}");
+            return Ok(ExitCode::FAILURE);
+        }
+    };
+    println!("is {verdict}");
+
+    history.record(day.year, day.day, part, &input, &answer, verdict.clone());
+    history.save(&history_path).map_err(|err| format!("{}: {err}", history_path.display()))?;
+
+    if verdict != Verdict::Right {
+        return Ok(ExitCode::FAILURE);
+    }
+
+    // Right answers are what `verify` checks against from now on.
+    let answers_path = answers::path(&dir);
+    let mut known = Answers::load(&answers_path).map_err(|err| format!("{}: {err}", answers_path.display()))?;
+    if known.get(day.year, day.day, part, &input).is_none() {
+        known.insert(day.year, day.day, part, &input, &aoc2022::Answer::Text(answer));
+        known.save(&answers_path).map_err(|err| format!("{}: {err}", answers_path.display()))?;
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
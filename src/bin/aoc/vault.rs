@@ -0,0 +1,169 @@
+use std::{fs, io, path::PathBuf, process::ExitCode};
+
+use aoc2022::{
+    input::{self, InputDir, InputError},
+    vault::{self, Key},
+};
+
+use crate::args::{self, Args};
+
+pub fn main(args: &[String]) -> Result<ExitCode, String> {
+    match args.first().map(String::as_str) {
+        Some("add") => add(&args[1..]),
+        Some("list") => list(&args[1..]),
+        Some("export") => export(&args[1..]),
+        Some(other) => Err(format!("unknown vault command `{other}`")),
+        None => Err("missing vault command".to_owned()),
+    }
+}
+
+fn add(args: &[String]) -> Result<ExitCode, String> {
+    let args = Args::parse(args, &["--input-dir"], &["--remove"])?;
+    args.expect_positional(1)?;
+
+    let year = args::year()?;
+    let dir = args.input_dir();
+    vault::init(&dir).map_err(|err| format!("{}: {err}", dir.dir().join("vault").display()))?;
+    let key = key(&dir)?;
+    // Any plain input can go in, not just those of registered days.
+    let days = match args.positional.first().map(String::as_str) {
+        Some("all") => (1..=25).filter(|&day| dir.path(year, day).exists()).collect(),
+        day => parse_day(day)?,
+    };
+    let mut code = ExitCode::SUCCESS;
+
+    for day in days {
+        let plain = dir.path(year, day);
+        let result = fs::read_to_string(&plain).and_then(|text| {
+            let path = vault::add(&dir, &key, year, day, &text)?;
+            if args.switch("--remove") {
+                // Only once what was written is known to give the input back.
+                let sealed = fs::read(&path)?;
+                if vault::open(&key, year, day, &sealed).as_deref() != Ok(text.as_str()) {
+                    return Err(io::Error::other(format!("{} does not open to the same input, kept it", path.display())));
+                }
+                fs::remove_file(&plain)?;
+            }
+            Ok(path)
+        });
+        match result {
+            Ok(path) => println!("{year} day {day:02}: sealed {}", path.display()),
+            Err(err) => {
+                eprintln!("{year} day {day:02}: {}: {err}", plain.display());
+                code = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    Ok(code)
+}
+
+fn list(args: &[String]) -> Result<ExitCode, String> {
+    let args = Args::parse(args, &["--input-dir"], &[])?;
+    args.expect_positional(0)?;
+
+    let dir = args.input_dir();
+    let entries = vault::list(&dir).map_err(|err| format!("{}: {err}", dir.dir().join("vault").display()))?;
+    if entries.is_empty() {
+        println!("the vault in {} is empty", dir.dir().display());
+        return Ok(ExitCode::SUCCESS);
+    }
+    let key = Key::from_env(&dir).map_err(|err| err.to_string())?;
+
+    let mut code = ExitCode::SUCCESS;
+    for (year, day, path) in entries {
+        let sealed = fs::read(&path).map_err(|err| format!("{}: {err}", path.display()))?;
+        let status = match &key {
+            None => "locked".to_owned(),
+            Some(key) => match vault::open(key, year, day, &sealed) {
+                Ok(text) => match input::read_file(dir.path(year, day)) {
+                    Ok(plain) if plain == input::normalise(&text) => "opens, plain copy matches".to_owned(),
+                    Ok(_) => "opens, plain copy differs".to_owned(),
+                    Err(InputError::Missing(_)) => "opens".to_owned(),
+                    Err(err) => format!("opens, {err}"),
+                },
+                Err(err) => {
+                    code = ExitCode::FAILURE;
+                    err.to_string()
+                }
+            },
+        };
+        println!("{year} day {day:02}  {:>7} bytes  {status}", sealed.len());
+    }
+
+    Ok(code)
+}
+
+fn export(args: &[String]) -> Result<ExitCode, String> {
+    let args = Args::parse(args, &["--input-dir"], &["--force"])?;
+    args.expect_positional(1)?;
+
+    let year = args::year()?;
+    let dir = args.input_dir();
+    let key = key(&dir)?;
+    let days = match args.positional.first().map(String::as_str) {
+        Some("all") => vault::list(&dir)
+            .map_err(|err| err.to_string())?
+            .into_iter()
+            .filter(|&(entry_year, _, _)| entry_year == year)
+            .map(|(_, day, _)| day)
+            .collect(),
+        day => parse_day(day)?,
+    };
+    let mut code = ExitCode::SUCCESS;
+
+    for day in days {
+        match export_day(&dir, &key, year, day, args.switch("--force")) {
+            Ok(Some(path)) => println!("{year} day {day:02}: wrote {}", path.display()),
+            Ok(None) => println!("{year} day {day:02}: already have {}", dir.path(year, day).display()),
+            Err(err) => {
+                eprintln!("{year} day {day:02}: {err}");
+                code = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    Ok(code)
+}
+
+/// Writes the vault's copy of `day` as a plain input, or `None` when the same
+/// input is already there. A different one is only replaced with `force`.
+fn export_day(dir: &InputDir, key: &Key, year: u32, day: u32, force: bool) -> Result<Option<PathBuf>, String> {
+    let sealed_path = vault::path(dir, year, day);
+    let sealed = fs::read(&sealed_path).map_err(|err| match err.kind() {
+        io::ErrorKind::NotFound => "not in the vault".to_owned(),
+        _ => format!("{}: {err}", sealed_path.display()),
+    })?;
+    let text = vault::open(key, year, day, &sealed).map_err(|err| format!("{}: {err}", sealed_path.display()))?;
+
+    let path = dir.path(year, day);
+    match input::read_file(&path) {
+        Ok(plain) if plain == input::normalise(&text) => return Ok(None),
+        Ok(_) if !force => return Err(format!("{} holds a different input, pass --force to replace it", path.display())),
+        Ok(_) | Err(InputError::Missing(_)) => {}
+        Err(err) => return Err(err.to_string()),
+    }
+
+    let write = || {
+        fs::create_dir_all(path.parent().expect("input paths have a parent"))?;
+        fs::write(&path, &text)
+    };
+    write().map_err(|err| format!("{}: {err}", path.display()))?;
+    Ok(Some(path))
+}
+
+fn key(dir: &InputDir) -> Result<Key, String> {
+    Key::from_env(dir)
+        .map_err(|err| err.to_string())?
+        .ok_or_else(|| format!("set {} to the vault passphrase", vault::KEY_VAR))
+}
+
+fn parse_day(day: Option<&str>) -> Result<Vec<u32>, String> {
+    match day {
+        Some(day) => match day.parse() {
+            Ok(day @ 1..=25) => Ok(vec![day]),
+            _ => Err(format!("invalid day `{day}`")),
+        },
+        None => Err("missing day".to_owned()),
+    }
+}
@@ -0,0 +1,63 @@
+use std::process::ExitCode;
+
+use aoc2022::answers::{self, Answers, Status};
+
+use crate::args::{self, Args};
+
+pub fn main(args: &[String]) -> Result<ExitCode, String> {
+    let args = Args::parse(args, &["--input-dir"], &["--record"])?;
+    args.expect_positional(1)?;
+
+    let days = args::days(args.positional.first())?;
+    let dir = args.input_dir();
+    let path = answers::path(&dir);
+    let mut answers = Answers::load(&path).map_err(|err| format!("{}: {err}", path.display()))?;
+
+    let mut code = ExitCode::SUCCESS;
+    let mut recorded = 0;
+
+    for day in days {
+        println!("{} day {:02}: {}", day.year, day.day, day.title);
+
+        let input = match dir.load(day.year, day.day) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("  {err}");
+                code = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        let results = match answers::verify(day, &input, &answers) {
+            Ok(results) => results,
+            Err(err) => {
+                eprintln!("  {err}");
+                code = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        for (part, answer, status) in results {
+            match (&answer, &status) {
+                (Ok(answer), Status::Unknown) if args.switch("--record") => {
+                    answers.insert(day.year, day.day, part, &input, answer);
+                    recorded += 1;
+                    println!("  Part {part}: recorded");
+                }
+                (Err(err), _) => println!("  Part {part}: {status}, {err}"),
+                _ => println!("  Part {part}: {status}"),
+            }
+
+            if matches!(status, Status::Fail(_) | Status::Error) {
+                code = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    if recorded > 0 {
+        answers.save(&path).map_err(|err| format!("{}: {err}", path.display()))?;
+        println!("recorded {recorded} answers in {}", path.display());
+    }
+
+    Ok(code)
+}
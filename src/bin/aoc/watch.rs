@@ -0,0 +1,41 @@
+use std::{process::ExitCode, thread, time::Duration};
+
+use aoc2022::{examples, watch};
+
+use crate::args::{self, Args};
+
+pub fn main(args: &[String]) -> Result<ExitCode, String> {
+    let args = Args::parse(args, &["--input-dir", "--interval"], &[])?;
+    args.expect_positional(1)?;
+
+    let day = match args::days(args.positional.first())?[..] {
+        [day] => day,
+        _ => return Err("watch takes a single day".to_owned()),
+    };
+    let interval = match args.value("--interval") {
+        Some(ms) => Duration::from_millis(ms.parse().map_err(|_| format!("invalid `--interval` value `{ms}`"))?),
+        None => Duration::from_millis(500),
+    };
+    let dir = args.input_dir();
+    let examples_dir = examples::dir(day.year);
+
+    let mut stamps = None;
+    let mut previous = watch::Snapshot::new();
+    let mut runs = 0;
+
+    loop {
+        let current = watch::stamps(&watch::watched(day, &dir, &examples_dir));
+        if stamps.as_ref() == Some(&current) {
+            thread::sleep(interval);
+            continue;
+        }
+        stamps = Some(current);
+        runs += 1;
+
+        let snapshot = watch::snapshot(day, &dir, &examples_dir);
+        println!("{} day {:02}: {} (run {runs})", day.year, day.day, day.title);
+        print!("{}", watch::diff(&previous, &snapshot));
+        println!("watching for changes, press Ctrl-C to stop");
+        previous = snapshot;
+    }
+}
@@ -0,0 +1,142 @@
+use std::{
+    collections::BTreeMap,
+    fmt, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    answers::{escape, input_hash, unescape},
+    error::{self, ParseError},
+    input::InputDir,
+    Day,
+};
+
+const HEADER: &str = "# year\tday\tpart\tsolver version\tinput hash\tanswer";
+
+/// Previously computed answers, keyed by year, day, part, the day's
+/// `Solution::VERSION` and a hash of the input. Unlike `Answers`, nothing in
+/// here is known to be right; it only saves solving the same input twice.
+#[derive(Debug, Default)]
+pub struct Cache {
+    entries: BTreeMap<(u32, u32, u32, u32, u64), String>,
+    changed: bool,
+}
+
+impl Cache {
+    /// Reads a cache file; a missing file, or one in an older format, is an
+    /// empty cache.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Cache> {
+        match std::fs::read_to_string(path) {
+            Ok(text) if !text.starts_with(HEADER) => Ok(Cache::default()),
+            Ok(text) => text.parse().map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Cache::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Writes the cache back if anything was stored since it was loaded.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        if self.changed {
+            std::fs::write(path, self.to_string())?;
+        }
+        Ok(())
+    }
+
+    pub fn get(&self, day: &Day, part: u32, input: &str) -> Option<&str> {
+        self.entries
+            .get(&(day.year, day.day, part, day.version, input_hash(input)))
+            .map(String::as_str)
+    }
+
+    /// Answers for all of `parts`, or `None` unless every one is cached.
+    pub fn get_all(&self, day: &Day, parts: &[u32], input: &str) -> Option<Vec<String>> {
+        parts
+            .iter()
+            .map(|&part| self.get(day, part, input).map(str::to_owned))
+            .collect()
+    }
+
+    pub fn insert(&mut self, day: &Day, part: u32, input: &str, answer: &str) {
+        let key = (day.year, day.day, part, day.version, input_hash(input));
+        if self.entries.get(&key).map(String::as_str) != Some(answer) {
+            self.entries.insert(key, answer.to_owned());
+            self.changed = true;
+        }
+    }
+}
+
+impl std::str::FromStr for Cache {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut cache = Cache::default();
+
+        for line in text.lines() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.splitn(6, '\t').collect();
+            let [year, day, part, version, hash, answer] = fields[..] else {
+                return Err(ParseError::at(
+                    text,
+                    line,
+                    "expected year, day, part, solver version, input hash and answer separated by tabs",
+                ));
+            };
+
+            let key = (
+                error::number(text, year)?,
+                error::number(text, day)?,
+                error::number(text, part)?,
+                error::number(text, version)?,
+                u64::from_str_radix(hash, 16).map_err(|_| ParseError::at(text, hash, "expected a hex input hash"))?,
+            );
+            cache.entries.insert(key, unescape(answer));
+        }
+
+        Ok(cache)
+    }
+}
+
+impl fmt::Display for Cache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{HEADER}")?;
+        for ((year, day, part, version, hash), answer) in &self.entries {
+            writeln!(f, "{year}\t{day}\t{part}\t{version}\t{hash:016x}\t{}", escape(answer))?;
+        }
+        Ok(())
+    }
+}
+
+/// The cache file that lives next to the inputs in `dir`.
+pub fn path(dir: &InputDir) -> PathBuf {
+    dir.dir().join("cache.txt")
+}
+
+#[test]
+fn test() {
+    let day02 = crate::day(2022, 2).unwrap();
+    let mut older = *day02;
+    older.version += 1;
+
+    let mut cache = Cache::default();
+    cache.insert(day02, 1, "A Y", "8");
+    cache.insert(day02, 2, "A Y", "#\n#");
+    assert!(cache.changed);
+
+    let cache: Cache = cache.to_string().parse().unwrap();
+    assert!(!cache.changed);
+    assert_eq!(cache.get(day02, 2, "A Y"), Some("#\n#"));
+    assert_eq!(cache.get(day02, 1, "A X"), None);
+    assert_eq!(cache.get(&older, 1, "A Y"), None);
+    assert_eq!(cache.get_all(day02, &[1, 2], "A Y"), Some(vec!["8".to_owned(), "#\n#".to_owned()]));
+    assert_eq!(cache.get_all(&older, &[1, 2], "A Y"), None);
+
+    let mut next_year = *day02;
+    next_year.year += 1;
+    assert_eq!(cache.get(&next_year, 1, "A Y"), None);
+
+    assert!("2022\t2\t1\t1\tzz\t8".parse::<Cache>().is_err());
+    assert!("2\t1\t1\t0\t8".parse::<Cache>().is_err());
+}
@@ -0,0 +1,167 @@
+//! Cooperative cancellation for solvers.
+//!
+//! Every part runs under a [`Token`] with a time budget, installed for the
+//! thread solving it. Solvers call [`check`] in their main loops and return
+//! its error once the token is cancelled, so a runaway part stops with
+//! [`Error::TimedOut`] instead of hanging. Solvers that never poll are not
+//! interrupted. The budget comes from `AOC_TIMEOUT` in seconds, or from
+//! `configure`, which the binary calls for `--timeout`.
+
+use std::{
+    cell::RefCell,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Once,
+    },
+    time::{Duration, Instant},
+};
+
+use crate::error::{Error, Result};
+
+pub const TIMEOUT_VAR: &str = "AOC_TIMEOUT";
+
+/// The budget when neither `AOC_TIMEOUT` nor `configure` sets one.
+pub const DEFAULT_BUDGET: Duration = Duration::from_secs(60);
+
+/// Cancelled once its deadline passes or `cancel` is called on any clone.
+#[derive(Debug, Clone)]
+pub struct Token {
+    budget: Option<Duration>,
+    start: Instant,
+    deadline: Option<Instant>,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl Token {
+    /// A token that expires `budget` from now; `None` never expires.
+    pub fn new(budget: Option<Duration>) -> Token {
+        Token {
+            budget,
+            start: Instant::now(),
+            deadline: budget.and_then(|budget| Instant::now().checked_add(budget)),
+            cancelled: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        if self.cancelled.load(Ordering::Relaxed) {
+            return true;
+        }
+        if self.deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+            self.cancel();
+            return true;
+        }
+        false
+    }
+
+    pub fn check(&self) -> Result<()> {
+        match self.is_cancelled() {
+            true => Err(Error::TimedOut(self.budget.unwrap_or_else(|| self.start.elapsed()))),
+            false => Ok(()),
+        }
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Token>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` with `token` as this thread's current token, restoring the
+/// previous one afterwards, even if `f` panics.
+pub fn within<T>(token: &Token, f: impl FnOnce() -> T) -> T {
+    struct Restore(Option<Token>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            CURRENT.with(|current| *current.borrow_mut() = self.0.take());
+        }
+    }
+
+    let _restore = Restore(CURRENT.with(|current| current.replace(Some(token.clone()))));
+    f()
+}
+
+/// Fails with `Error::TimedOut` once the current token is cancelled; always
+/// succeeds outside `within`.
+pub fn check() -> Result<()> {
+    CURRENT.with(|current| match &*current.borrow() {
+        Some(token) => token.check(),
+        None => Ok(()),
+    })
+}
+
+/// Budget in milliseconds; 0 means none and u64::MAX unset.
+static BUDGET: AtomicU64 = AtomicU64::new(u64::MAX);
+static FROM_ENV: Once = Once::new();
+
+/// Sets the per-part budget; `None` lets parts run for as long as they take.
+pub fn configure(budget: Option<Duration>) {
+    FROM_ENV.call_once(|| {});
+    store(budget);
+}
+
+/// The per-part budget set by `configure` or `AOC_TIMEOUT`, `DEFAULT_BUDGET`
+/// otherwise.
+pub fn budget() -> Option<Duration> {
+    FROM_ENV.call_once(|| {
+        if let Ok(seconds) = std::env::var(TIMEOUT_VAR) {
+            match parse_budget(&seconds) {
+                Ok(budget) => store(budget),
+                Err(err) => eprintln!("ignoring {TIMEOUT_VAR}: {err}"),
+            }
+        }
+    });
+
+    match BUDGET.load(Ordering::Relaxed) {
+        u64::MAX => Some(DEFAULT_BUDGET),
+        0 => None,
+        millis => Some(Duration::from_millis(millis)),
+    }
+}
+
+fn store(budget: Option<Duration>) {
+    let millis = budget.map_or(0, |budget| budget.as_millis().clamp(1, u64::MAX as u128 - 1) as u64);
+    BUDGET.store(millis, Ordering::Relaxed);
+}
+
+/// Seconds, possibly fractional, or `0` or `off` for no budget.
+pub fn parse_budget(seconds: &str) -> std::result::Result<Option<Duration>, String> {
+    if seconds == "off" {
+        return Ok(None);
+    }
+
+    match seconds.parse::<f64>() {
+        Ok(0.0) => Ok(None),
+        Ok(seconds) if seconds > 0.0 && seconds.is_finite() => Ok(Some(Duration::from_secs_f64(seconds))),
+        _ => Err(format!("invalid timeout `{seconds}`, expected seconds or `off`")),
+    }
+}
+
+#[test]
+fn test() {
+    assert!(check().is_ok());
+
+    let expired = Token::new(Some(Duration::ZERO));
+    let err = within(&expired, || within(&Token::new(None), check).and(check())).unwrap_err();
+    assert_eq!(err.to_string(), "timed out after 0ns");
+    assert!(check().is_ok());
+
+    let panicked = std::panic::catch_unwind(|| within(&expired, || panic!("solver bug")));
+    assert!(panicked.is_err());
+    assert!(check().is_ok());
+
+    let token = Token::new(None);
+    assert!(!token.is_cancelled());
+    token.clone().cancel();
+    assert!(token.is_cancelled());
+
+    assert_eq!(parse_budget("1.5"), Ok(Some(Duration::from_millis(1500))));
+    assert_eq!(parse_budget("off"), Ok(None));
+    assert_eq!(parse_budget("0"), Ok(None));
+    assert!(parse_budget("-1").is_err());
+    assert!(parse_budget("soon").is_err());
+}
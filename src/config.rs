@@ -0,0 +1,137 @@
+//! Settings for talking to the Advent of Code site.
+//!
+//! Read from `$AOC_CONFIG`, or `aoc/config` under `$XDG_CONFIG_HOME` or
+//! `~/.config`, as `key = value` lines. The session token can also come from
+//! `$AOC_SESSION`, which wins over the file so it can be kept out of it.
+
+use std::{
+    io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::error::ParseError;
+
+pub const CONFIG_VAR: &str = "AOC_CONFIG";
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The site asks automated tools to identify themselves.
+pub const DEFAULT_USER_AGENT: &str = concat!("aoc2022/", env!("CARGO_PKG_VERSION"), " (std Rust input fetcher)");
+
+/// The least time between two requests to the site.
+pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    /// The value of the `session` cookie of a logged in browser.
+    pub session: Option<String>,
+    pub base_url: String,
+    pub user_agent: String,
+    pub interval: Duration,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            session: None,
+            base_url: DEFAULT_BASE_URL.to_owned(),
+            user_agent: DEFAULT_USER_AGENT.to_owned(),
+            interval: DEFAULT_INTERVAL,
+        }
+    }
+}
+
+impl Config {
+    /// The config file, if there is one, with `$AOC_SESSION` applied.
+    pub fn from_env() -> io::Result<Config> {
+        let mut config = match path() {
+            Some(path) => Config::load(&path)?,
+            None => Config::default(),
+        };
+
+        if let Ok(session) = std::env::var(SESSION_VAR) {
+            if !session.trim().is_empty() {
+                config.session = Some(session.trim().to_owned());
+            }
+        }
+
+        Ok(config)
+    }
+
+    /// Reads a config file; a missing file gives the defaults.
+    pub fn load(path: &Path) -> io::Result<Config> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => text
+                .parse()
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {err}", path.display()))),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+impl std::str::FromStr for Config {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Config, ParseError> {
+        let mut config = Config::default();
+
+        for line in text.lines() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            let Some((key, value)) = trimmed.split_once('=') else {
+                return Err(ParseError::at(text, line, "expected `key = value`"));
+            };
+            let value = value.trim().to_owned();
+
+            match key.trim() {
+                "session" => config.session = Some(value).filter(|session| !session.is_empty()),
+                "base_url" => config.base_url = value.trim_end_matches('/').to_owned(),
+                "user_agent" => config.user_agent = value,
+                "interval" => {
+                    config.interval = match value.parse::<f64>() {
+                        Ok(seconds) if seconds >= 0.0 && seconds.is_finite() => Duration::from_secs_f64(seconds),
+                        _ => return Err(ParseError::at(text, line, "expected the interval in seconds")),
+                    }
+                }
+                _ => return Err(ParseError::at(text, line, "unknown setting")),
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+/// Where the config file is looked for.
+pub fn path() -> Option<PathBuf> {
+    let var = |name| std::env::var_os(name).filter(|value| !value.is_empty()).map(PathBuf::from);
+
+    var(CONFIG_VAR)
+        .or_else(|| var("XDG_CONFIG_HOME").map(|dir| dir.join("aoc/config")))
+        .or_else(|| var("HOME").map(|home| home.join(".config/aoc/config")))
+}
+
+#[test]
+fn test() {
+    let config: Config = "# mine\nsession = 53616c74\nbase_url = http://localhost:8080/\n\ninterval = 0.5\n"
+        .parse()
+        .unwrap();
+    assert_eq!(config.session.as_deref(), Some("53616c74"));
+    assert_eq!(config.base_url, "http://localhost:8080");
+    assert_eq!(config.user_agent, DEFAULT_USER_AGENT);
+    assert_eq!(config.interval, Duration::from_millis(500));
+
+    assert_eq!("session =".parse::<Config>().unwrap().session, None);
+
+    let err = "session = x\ncolour = blue".parse::<Config>().unwrap_err();
+    assert_eq!(err.to_string(), "line 2, column 1: unknown setting, found `colour = blue`");
+    assert!("interval = soon".parse::<Config>().is_err());
+    assert!("session".parse::<Config>().is_err());
+
+    assert_eq!(Config::load(Path::new("does-not-exist")).unwrap(), Config::default());
+}
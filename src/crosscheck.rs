@@ -0,0 +1,100 @@
+use std::fmt;
+
+use crate::{error::Result, Day};
+
+/// Every implementation's answer to one part of one input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comparison {
+    pub part: u32,
+    /// By implementation name, the default first. Failures hold their error
+    /// message, so implementations that fail the same way agree.
+    pub answers: Vec<(&'static str, std::result::Result<String, String>)>,
+}
+
+impl Comparison {
+    pub fn agrees(&self) -> bool {
+        self.answers.windows(2).all(|pair| pair[0].1 == pair[1].1)
+    }
+}
+
+impl fmt::Display for Comparison {
+    /// `part 1: 7 from default, sliding`, or every answer on its own line
+    /// under `part 1: MISMATCH` when they diverge.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let show = |answer: &std::result::Result<String, String>| match answer {
+            Ok(answer) if answer.contains('\n') => format!("\n{answer}"),
+            Ok(answer) => answer.clone(),
+            Err(err) => format!("error: {err}"),
+        };
+
+        if self.agrees() {
+            let names: Vec<&str> = self.answers.iter().map(|(name, _)| *name).collect();
+            return write!(f, "part {}: {} from {}", self.part, show(&self.answers[0].1), names.join(", "));
+        }
+
+        write!(f, "part {}: MISMATCH", self.part)?;
+        for (name, answer) in &self.answers {
+            write!(f, "\n  {name}: {}", show(answer))?;
+        }
+        Ok(())
+    }
+}
+
+/// Days with more than one implementation of either part.
+pub fn candidates(days: &[Day]) -> Vec<&Day> {
+    days.iter()
+        .filter(|day| [1, 2].iter().any(|&part| day.variants(part).len() > 1))
+        .collect()
+}
+
+/// Parses `input` once and solves each part with every implementation.
+pub fn compare(day: &Day, input: &str) -> Result<Vec<Comparison>> {
+    let parsed = day.parse(input)?;
+
+    Ok([1, 2]
+        .into_iter()
+        .map(|part| Comparison {
+            part,
+            answers: day
+                .variants(part)
+                .into_iter()
+                .map(|name| {
+                    let answer = day.variant(part, name, &parsed).expect("listed variant exists");
+                    (name, answer.map(|answer| answer.to_string()).map_err(|err| err.to_string()))
+                })
+                .collect(),
+        })
+        .collect())
+}
+
+#[test]
+fn test() {
+    let day06 = crate::day(2022, 6).unwrap();
+    assert_eq!(day06.variants(1), ["default", "sliding"]);
+    assert!(day06.variants(3).is_empty());
+
+    let comparisons = compare(day06, "mjqjpqmgbljsphdztnvjfqwrcgsmlb").unwrap();
+    assert!(comparisons.iter().all(Comparison::agrees));
+    assert_eq!(comparisons[0].to_string(), "part 1: 7 from default, sliding");
+
+    let mismatch = Comparison {
+        part: 2,
+        answers: vec![("default", Ok("19".to_owned())), ("sliding", Err("no answer: none".to_owned()))],
+    };
+    assert!(!mismatch.agrees());
+    assert_eq!(mismatch.to_string(), "part 2: MISMATCH\n  default: 19\n  sliding: error: no answer: none");
+
+    assert!(candidates(crate::DAYS).iter().any(|day| day.day == 12));
+}
+
+#[test]
+fn examples() {
+    for year in crate::years() {
+        for example in crate::examples::discover(&crate::examples::dir(year)).unwrap() {
+            let day = crate::day(year, example.day).unwrap();
+            for comparison in compare(day, &example.input).unwrap() {
+                assert!(comparison.agrees(), "{}: {comparison}", example.path.display());
+            }
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use std::{fmt, str::FromStr, time::Duration};
+
+use crate::input::InputError;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug)]
+pub enum Error {
+    Parse(ParseError),
+    Input(InputError),
+    /// The input parsed, but has no answer, e.g. a day12 map with no path.
+    NoAnswer(String),
+    /// The part ran past its time budget; see `cancel`.
+    TimedOut(Duration),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse(err) => write!(f, "parse error: {err}"),
+            Error::Input(err) => err.fmt(f),
+            Error::NoAnswer(reason) => write!(f, "no answer: {reason}"),
+            Error::TimedOut(budget) => write!(f, "timed out after {budget:?}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(err: InputError) -> Self {
+        Error::Input(err)
+    }
+}
+
+/// Malformed input. `line` and `column` are 1-based and count characters,
+/// `text` is the offending fragment (empty when something was missing).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// An error about `fragment`, which must be a subslice of `source`; the
+    /// position is where `fragment` starts within `source`.
+    pub fn at(source: &str, fragment: &str, message: impl Into<String>) -> ParseError {
+        let (line, column) = position(source, fragment);
+
+        ParseError {
+            line,
+            column,
+            text: fragment.to_owned(),
+            message: message.into(),
+        }
+    }
+
+    /// An error about something missing right after `fragment`.
+    pub fn after(source: &str, fragment: &str, message: impl Into<String>) -> ParseError {
+        let end = offset(source, fragment) + fragment.len();
+
+        ParseError::at(source, &source[end..end], message)
+    }
+
+    /// Moves an error reported against a single line to line `line` of the
+    /// whole input.
+    pub fn on_line(mut self, line: usize) -> ParseError {
+        self.line += line - 1;
+        self
+    }
+
+    /// Rebases an error reported against `inner` onto `outer`, where `inner`
+    /// is a subslice of `outer`.
+    pub fn within(mut self, outer: &str, inner: &str) -> ParseError {
+        let (line, column) = position(outer, inner);
+
+        if self.line == 1 {
+            self.column += column - 1;
+        }
+        self.line += line - 1;
+
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.message)?;
+
+        if self.text.is_empty() {
+            write!(f, ", found nothing")
+        } else {
+            write!(f, ", found `{}`", self.text)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `token`, a subslice of `source`, as a number.
+pub fn number<T: FromStr>(source: &str, token: &str) -> std::result::Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(source, token, "expected a number"))
+}
+
+fn offset(source: &str, fragment: &str) -> usize {
+    let offset = (fragment.as_ptr() as usize).wrapping_sub(source.as_ptr() as usize);
+
+    debug_assert!(offset <= source.len(), "fragment is not part of source");
+    offset.min(source.len())
+}
+
+fn position(source: &str, fragment: &str) -> (usize, usize) {
+    let before = &source[..offset(source, fragment)];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+#[test]
+fn test() {
+    let source = "ab\ncd ef\ngh";
+    let line = source.lines().nth(1).unwrap();
+    let word = line.split(' ').nth(1).unwrap();
+
+    let err = ParseError::at(source, word, "expected a number");
+    assert_eq!((err.line, err.column, err.text.as_str()), (2, 4, "ef"));
+    assert_eq!(
+        err.to_string(),
+        "line 2, column 4: expected a number, found `ef`"
+    );
+
+    let err = ParseError::at(line, word, "expected a number").within(source, line);
+    assert_eq!((err.line, err.column), (2, 4));
+
+    let err = ParseError::after(line, word, "expected a size").within(source, line);
+    assert_eq!((err.line, err.column, err.text.as_str()), (2, 6, ""));
+    assert_eq!(
+        err.to_string(),
+        "line 2, column 6: expected a size, found nothing"
+    );
+}
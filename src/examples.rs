@@ -0,0 +1,147 @@
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    answers::{self, Status},
+    error::{self, ParseError, Result},
+    input, Answer, Day,
+};
+
+/// A puzzle example read from `dayNN-<name>.txt`, with the expected answers
+/// from `dayNN-<name>.answers` next to it. Either part may be left out, and
+/// an example with no answers file is only parsed.
+#[derive(Debug)]
+pub struct Example {
+    pub day: u32,
+    pub name: String,
+    pub path: PathBuf,
+    pub input: String,
+    pub expected: BTreeMap<u32, String>,
+}
+
+impl Example {
+    /// Solves the example with `day`, checking every part that has an
+    /// expected answer.
+    pub fn check(&self, day: &Day) -> Result<Vec<(u32, Result<Answer>, Status)>> {
+        let parsed = day.parse(&self.input)?;
+
+        Ok(self
+            .expected
+            .iter()
+            .filter_map(|(&part, expected)| {
+                let answer = day.part(part, &parsed)?;
+                let status = match &answer {
+                    Ok(answer) if answer.to_string() == *expected => Status::Pass,
+                    _ => Status::Fail(expected.clone()),
+                };
+                Some((part, answer, status))
+            })
+            .collect())
+    }
+}
+
+/// The examples for `year` checked into this repository.
+pub fn dir(year: u32) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("input/{year}/examples"))
+}
+
+/// Every example in `dir`, ordered by day and name. Other files are ignored.
+pub fn discover(dir: &Path) -> io::Result<Vec<Example>> {
+    let mut examples = vec![];
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let Some((day, name)) = path.file_name().and_then(|name| name.to_str()).and_then(file_name) else {
+            continue;
+        };
+
+        let input = input::read_file(&path).map_err(|err| io::Error::other(err.to_string()))?;
+        let expected = match fs::read_to_string(path.with_extension("answers")) {
+            Ok(text) => expected(&text).map_err(|err| {
+                io::Error::new(io::ErrorKind::InvalidData, format!("{}: {err}", path.with_extension("answers").display()))
+            })?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(err) => return Err(err),
+        };
+
+        examples.push(Example { day, name, path, input, expected });
+    }
+
+    examples.sort_by(|a, b| (a.day, &a.name).cmp(&(b.day, &b.name)));
+
+    Ok(examples)
+}
+
+/// Splits `dayNN-<name>.txt` into the day and name.
+fn file_name(file_name: &str) -> Option<(u32, String)> {
+    let (day, name) = file_name.strip_prefix("day")?.strip_suffix(".txt")?.split_once('-')?;
+
+    Some((day.parse().ok()?, name.to_owned()))
+}
+
+/// Parses lines of `part<TAB>answer`, escaped as in the answers file.
+fn expected(text: &str) -> std::result::Result<BTreeMap<u32, String>, ParseError> {
+    let mut expected = BTreeMap::new();
+
+    for line in text.lines() {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let Some((part, answer)) = line.split_once('\t') else {
+            return Err(ParseError::at(text, line, "expected a part and answer separated by a tab"));
+        };
+        let part = error::number(text, part)?;
+        if !(1..=2).contains(&part) {
+            return Err(ParseError::at(text, line, "expected part 1 or 2"));
+        }
+
+        expected.insert(part, answers::unescape(answer));
+    }
+
+    Ok(expected)
+}
+
+#[test]
+fn test() {
+    assert_eq!(file_name("day06-3.txt"), Some((6, "3".to_owned())));
+    assert_eq!(file_name("day10-small.answers"), None);
+    assert_eq!(file_name("day10.txt"), None);
+
+    let parsed = expected("# part\tanswer\n1\t13140\n2\t#.\\n.#\n").unwrap();
+    assert_eq!(parsed[&1], "13140");
+    assert_eq!(parsed[&2], "#.\n.#");
+
+    let err = expected("1\t2\n3\t4").unwrap_err();
+    assert_eq!(err.to_string(), "line 2, column 1: expected part 1 or 2, found `3\t4`");
+}
+
+#[test]
+fn examples() {
+    let mut failures = vec![];
+
+    for year in crate::years() {
+        for example in discover(&dir(year)).unwrap() {
+            let Some(day) = crate::day(year, example.day) else {
+                failures.push(format!("{}: {year} day {} is not registered", example.path.display(), example.day));
+                continue;
+            };
+
+            match example.check(day) {
+                Ok(results) => {
+                    for (part, answer, status) in results {
+                        if status != Status::Pass {
+                            failures.push(format!("{} part {part}: got {answer:?}, {status}", example.path.display()));
+                        }
+                    }
+                }
+                Err(err) => failures.push(format!("{}: {err}", example.path.display())),
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "failing examples:\n{}", failures.join("\n"));
+}
@@ -0,0 +1,216 @@
+//! Downloading puzzle inputs from the Advent of Code site.
+//!
+//! A [`Client`] sends the session token from [`Config`] with every request,
+//! leaves at least the configured interval between requests, recorded in
+//! `.last-request` in the input directory so separate runs respect it too,
+//! and never asks for an input it already has or a puzzle that has not
+//! unlocked yet.
+
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{config::Config, http, input::InputDir};
+
+#[derive(Debug)]
+pub enum FetchError {
+    NoSession,
+    /// The session token was refused, most likely because it expired.
+    BadSession,
+    Locked { year: u32, day: u32 },
+    Status(u16, String),
+    Http(io::Error),
+    Io(PathBuf, io::Error),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::NoSession => write!(
+                f,
+                "no session token, set `session` in the config file or {}",
+                crate::config::SESSION_VAR
+            ),
+            FetchError::BadSession => write!(f, "the session token was refused, log in again and update it"),
+            FetchError::Locked { year, day } => write!(f, "{year} day {day} has not unlocked yet"),
+            FetchError::Status(status, body) => write!(f, "the site answered {status}: {}", first_line(body)),
+            FetchError::Http(err) => write!(f, "request failed: {err}"),
+            FetchError::Io(path, err) => write!(f, "could not write {}: {err}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+fn first_line(text: &str) -> &str {
+    text.trim().lines().next().unwrap_or("")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was already there; nothing was requested.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+pub struct Client {
+    pub config: Config,
+    pub dir: InputDir,
+}
+
+impl Client {
+    pub fn new(config: Config, dir: InputDir) -> Client {
+        Client { config, dir }
+    }
+
+    /// Downloads the input for `day` of `year` to where `InputDir` loads it
+    /// from, unless it is already there or `force` is set.
+    pub fn input(&self, year: u32, day: u32, force: bool) -> Result<Fetched, FetchError> {
+        let path = self.dir.path(year, day);
+        if !force && fs::metadata(&path).is_ok_and(|meta| meta.len() > 0) {
+            return Ok(Fetched::Cached(path));
+        }
+        if SystemTime::now() < unlocks_at(year, day) {
+            return Err(FetchError::Locked { year, day });
+        }
+
+        let response = self.get(&format!("/{year}/day/{day}/input"))?;
+        match response.status {
+            200 if !response.body.is_empty() => {}
+            404 => return Err(FetchError::Locked { year, day }),
+            _ => return Err(FetchError::Status(response.status, response.body)),
+        }
+
+        write_atomically(&path, &response.body).map_err(|err| FetchError::Io(path.clone(), err))?;
+        Ok(Fetched::Downloaded(path))
+    }
+
+    pub fn get(&self, path: &str) -> Result<http::Response, FetchError> {
+        self.request("GET", path, None)
+    }
+
+    /// Posts `form`, already URL encoded.
+    pub fn post(&self, path: &str, form: &str) -> Result<http::Response, FetchError> {
+        self.request("POST", path, Some(form))
+    }
+
+    fn request(&self, method: &str, path: &str, form: Option<&str>) -> Result<http::Response, FetchError> {
+        let session = self.config.session.as_deref().ok_or(FetchError::NoSession)?;
+        let cookie = format!("session={session}");
+        let mut headers = vec![("Cookie", cookie.as_str()), ("User-Agent", self.config.user_agent.as_str())];
+        if form.is_some() {
+            headers.push(("Content-Type", "application/x-www-form-urlencoded"));
+        }
+
+        self.throttle().map_err(|err| FetchError::Io(self.stamp(), err))?;
+        let url = format!("{}{path}", self.config.base_url);
+        let response = http::request(method, &url, &headers, form).map_err(FetchError::Http)?;
+
+        // The site answers a missing or expired session with a 400 or 500
+        // and a page asking to log in.
+        if response.status >= 400 && response.body.contains("log in") {
+            return Err(FetchError::BadSession);
+        }
+        Ok(response)
+    }
+
+    fn stamp(&self) -> PathBuf {
+        self.dir.dir().join(".last-request")
+    }
+
+    /// Waits until the interval since the last request has passed, then
+    /// records this one.
+    fn throttle(&self) -> io::Result<()> {
+        let stamp = self.stamp();
+
+        if let Some(last) = fs::read_to_string(&stamp).ok().and_then(|text| text.trim().parse().ok()) {
+            let since = now_millis().saturating_sub(last);
+            let wait = self.config.interval.saturating_sub(Duration::from_millis(since));
+            thread::sleep(wait);
+        }
+
+        fs::create_dir_all(self.dir.dir())?;
+        fs::write(stamp, now_millis().to_string())
+    }
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |since| since.as_millis() as u64)
+}
+
+/// Writes through a temporary file, so an interrupted download never
+/// leaves a partial input behind.
+fn write_atomically(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let temporary = path.with_extension("part");
+    fs::write(&temporary, contents)?;
+    fs::rename(temporary, path)
+}
+
+/// Puzzles unlock at midnight US Eastern time, 05:00 UTC, on day `day` of
+/// December.
+pub fn unlocks_at(year: u32, day: u32) -> SystemTime {
+    // Days from 1970-01-01 to December `day` of `year`, from Howard
+    // Hinnant's `days_from_civil` with March-based years.
+    let (y, m, d) = (year as i64, 12, day as i64);
+    let era = y.div_euclid(400);
+    let year_of_era = y - era * 400;
+    let day_of_year = (153 * (m - 3) + 2) / 5 + d - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146097 + day_of_era - 719468;
+
+    UNIX_EPOCH + Duration::from_secs((days * 86400 + 5 * 3600).max(0) as u64)
+}
+
+#[test]
+fn test() {
+    assert_eq!(unlocks_at(2022, 1), UNIX_EPOCH + Duration::from_secs(1669870800));
+    assert_eq!(unlocks_at(2015, 25), UNIX_EPOCH + Duration::from_secs(1451019600));
+
+    let server = crate::mock::Server::start(|request| match (request.path.as_str(), request.header("cookie")) {
+        (_, Some(cookie)) if cookie != "session=good" => (400, "Puzzle inputs differ by user.  Please log in to get your puzzle input.".to_owned()),
+        ("/2022/day/2/input", _) => (200, "A Y\nB X\nC Z\n".to_owned()),
+        ("/2022/day/3/input", _) => (500, "Internal Server Error".to_owned()),
+        _ => (404, "Not Found".to_owned()),
+    });
+
+    let root = std::env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+    let config = Config {
+        session: Some("good".to_owned()),
+        base_url: server.url(),
+        interval: Duration::from_millis(50),
+        ..Config::default()
+    };
+    let client = Client::new(config.clone(), InputDir::new(&root));
+
+    let path = root.join("2022/day02.txt");
+    assert_eq!(client.input(2022, 2, false).unwrap(), Fetched::Downloaded(path.clone()));
+    assert_eq!(fs::read_to_string(&path).unwrap(), "A Y\nB X\nC Z\n");
+    assert_eq!(client.dir.load(2022, 2).unwrap(), "A Y\nB X\nC Z");
+    assert_eq!(client.input(2022, 2, false).unwrap(), Fetched::Cached(path.clone()));
+
+    let started = std::time::Instant::now();
+    assert!(matches!(client.input(2022, 2, true), Ok(Fetched::Downloaded(_))));
+    assert!(matches!(client.input(2022, 3, false), Err(FetchError::Status(500, _))));
+    assert!(started.elapsed() >= Duration::from_millis(50));
+    assert!(!root.join("2022/day03.txt").exists());
+
+    assert!(matches!(client.input(2022, 4, false), Err(FetchError::Locked { year: 2022, day: 4 })));
+    assert!(matches!(client.input(2099, 1, false), Err(FetchError::Locked { .. })));
+
+    let refused = Client::new(Config { session: Some("stale".to_owned()), ..config.clone() }, InputDir::new(&root));
+    assert!(matches!(refused.input(2022, 5, false), Err(FetchError::BadSession)));
+    let anonymous = Client::new(Config { session: None, ..config }, InputDir::new(&root));
+    assert!(matches!(anonymous.input(2022, 5, false), Err(FetchError::NoSession)));
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 5);
+    assert_eq!(requests[0].header("user-agent"), Some(crate::config::DEFAULT_USER_AGENT));
+
+    fs::remove_dir_all(root).unwrap();
+}
@@ -0,0 +1,194 @@
+//! Just enough HTTP to talk to the Advent of Code site.
+//!
+//! Plain `http://` URLs, which tests point at a local server, are spoken
+//! directly over a `TcpStream`. The real site is `https://`, and rather than
+//! carry a TLS stack those requests go through the `curl` command, with
+//! headers fed to it on stdin so the session token never shows up in the
+//! process list.
+
+use std::{
+    io::{self, Read, Write},
+    net::TcpStream,
+    process::{Command, Stdio},
+    time::Duration,
+};
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Sends a request and returns whatever the server answers, error statuses
+/// included; only failing to get an answer at all is an error.
+pub fn request(method: &str, url: &str, headers: &[(&str, &str)], body: Option<&str>) -> io::Result<Response> {
+    if url.starts_with("https://") {
+        return curl(method, url, headers, body);
+    }
+    let Some(rest) = url.strip_prefix("http://") else {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("unsupported URL `{url}`")));
+    };
+
+    let (host, path) = match rest.find('/') {
+        Some(slash) => rest.split_at(slash),
+        None => (rest, "/"),
+    };
+    let address = if host.contains(':') { host.to_owned() } else { format!("{host}:80") };
+
+    let mut stream = TcpStream::connect(address)?;
+    stream.set_read_timeout(Some(TIMEOUT))?;
+    stream.set_write_timeout(Some(TIMEOUT))?;
+
+    let mut request = format!("{method} {path} HTTP/1.1\r\nHost: {host}\r\nConnection: close\r\n");
+    for (name, value) in headers {
+        request.push_str(&format!("{name}: {value}\r\n"));
+    }
+    if let Some(body) = body {
+        request.push_str(&format!("Content-Length: {}\r\n", body.len()));
+    }
+    request.push_str("\r\n");
+    request.push_str(body.unwrap_or(""));
+    stream.write_all(request.as_bytes())?;
+
+    let mut response = vec![];
+    stream.read_to_end(&mut response)?;
+
+    parse_response(&response)
+}
+
+/// Percent-encodes `text` for an `application/x-www-form-urlencoded` body.
+pub fn form_encode(text: &str) -> String {
+    text.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (byte as char).to_string(),
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+fn parse_response(response: &[u8]) -> io::Result<Response> {
+    let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidData, message.to_owned());
+
+    let end = response
+        .windows(4)
+        .position(|window| window == b"\r\n\r\n")
+        .ok_or_else(|| invalid("response has no end of headers"))?;
+    let head = String::from_utf8_lossy(&response[..end]);
+    let mut body = &response[end + 4..];
+
+    let mut lines = head.split("\r\n");
+    let status = lines
+        .next()
+        .and_then(|line| line.split(' ').nth(1))
+        .and_then(|status| status.parse().ok())
+        .ok_or_else(|| invalid("response has no status"))?;
+
+    let mut decoded = vec![];
+    let chunked = lines.any(|line| {
+        line.split_once(':').is_some_and(|(name, value)| {
+            name.eq_ignore_ascii_case("transfer-encoding") && value.trim().eq_ignore_ascii_case("chunked")
+        })
+    });
+    if chunked {
+        loop {
+            let line_end = body.windows(2).position(|w| w == b"\r\n").ok_or_else(|| invalid("truncated chunk"))?;
+            let size = std::str::from_utf8(&body[..line_end]).ok().map(|size| size.split(';').next().unwrap_or("").trim());
+            let size = size
+                .and_then(|size| usize::from_str_radix(size, 16).ok())
+                .ok_or_else(|| invalid("bad chunk size"))?;
+            body = &body[line_end + 2..];
+            if size == 0 {
+                break;
+            }
+            decoded.extend_from_slice(body.get(..size).ok_or_else(|| invalid("truncated chunk"))?);
+            body = body.get(size + 2..).unwrap_or_default();
+        }
+        body = &decoded;
+    }
+
+    Ok(Response {
+        status,
+        body: String::from_utf8_lossy(body).into_owned(),
+    })
+}
+
+fn curl(method: &str, url: &str, headers: &[(&str, &str)], body: Option<&str>) -> io::Result<Response> {
+    let mut config = format!(
+        "silent\nshow-error\nrequest = {}\nurl = {}\nmax-time = {}\nwrite-out = \"\\n%{{http_code}}\"\n",
+        quote(method)?,
+        quote(url)?,
+        TIMEOUT.as_secs()
+    );
+    for (name, value) in headers {
+        config.push_str(&format!("header = {}\n", quote(&format!("{name}: {value}"))?));
+    }
+    if let Some(body) = body {
+        config.push_str(&format!("data-binary = {}\n", quote(body)?));
+    }
+
+    let mut child = Command::new("curl")
+        .args(["--config", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| io::Error::new(err.kind(), format!("could not run curl, which https needs: {err}")))?;
+    child.stdin.take().expect("stdin is piped").write_all(config.as_bytes())?;
+    let output = child.wait_with_output()?;
+
+    if !output.status.success() {
+        return Err(io::Error::other(format!("curl: {}", String::from_utf8_lossy(&output.stderr).trim())));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let (body, status) = stdout.rsplit_once('\n').unwrap_or(("", &stdout));
+    let status = status
+        .trim()
+        .parse()
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, format!("curl gave no status: {status}")))?;
+
+    Ok(Response {
+        status,
+        body: body.to_owned(),
+    })
+}
+
+/// `text` as a quoted value for curl's config file. Control characters are
+/// refused, since a line break would start a new option.
+fn quote(text: &str) -> io::Result<String> {
+    if text.chars().any(char::is_control) {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "control character in a request"));
+    }
+    Ok(format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\"")))
+}
+
+#[test]
+fn test() {
+    let response = parse_response(b"HTTP/1.1 200 OK\r\nContent-Length: 5\r\n\r\n1\n2\n3").unwrap();
+    assert_eq!(response, Response { status: 200, body: "1\n2\n3".to_owned() });
+
+    let chunked = parse_response(b"HTTP/1.1 404 Not Found\r\nTransfer-Encoding: chunked\r\n\r\n4\r\nnot \r\n5\r\nfound\r\n0\r\n\r\n");
+    assert_eq!(chunked.unwrap(), Response { status: 404, body: "not found".to_owned() });
+
+    assert!(parse_response(b"HTTP/1.1 200 OK\r\n").is_err());
+    assert_eq!(form_encode("RZHFGJCB"), "RZHFGJCB");
+    assert_eq!(form_encode("a b&c=é"), "a%20b%26c%3D%C3%A9");
+    assert!(request("GET", "ftp://example.com", &[], None).is_err());
+    assert_eq!(quote(r#"a"b\c"#).unwrap(), r#""a\"b\\c""#);
+    assert!(quote("abc\nurl = http://elsewhere").is_err());
+    assert!(request("GET", "https://example.com", &[("Cookie", "session=abc\r\nX: 1")], None).is_err());
+
+    let server = crate::mock::Server::start(|request| match request.path.as_str() {
+        "/echo" => (200, format!("{} {}", request.method, request.body)),
+        _ => (404, "nope".to_owned()),
+    });
+    let response = request("POST", &format!("{}/echo", server.url()), &[("Cookie", "session=abc")], Some("a=1")).unwrap();
+    assert_eq!(response, Response { status: 200, body: "POST a=1".to_owned() });
+    assert_eq!(request("GET", &server.url(), &[], None).unwrap().status, 404);
+
+    let requests = server.requests();
+    assert_eq!(requests[0].header("cookie"), Some("session=abc"));
+    assert_eq!(requests[1].path, "/");
+}
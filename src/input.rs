@@ -0,0 +1,134 @@
+use std::{
+    fmt,
+    io::{self, Read, Write},
+    path::{Path, PathBuf},
+};
+
+use crate::vault::{self, VaultError};
+
+/// Overrides the directory puzzle inputs are read from.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+const DEFAULT_INPUT_DIR: &str = "input";
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing(PathBuf),
+    Io(PathBuf, io::Error),
+    /// The input is only in the vault and `$AOC_VAULT_KEY` is not set.
+    Locked(PathBuf),
+    Vault(PathBuf, VaultError),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Missing(path) => write!(
+                f,
+                "no input at {} (place it there or set {INPUT_DIR_VAR})",
+                path.display()
+            ),
+            InputError::Io(path, err) => write!(f, "could not read {}: {err}", path.display()),
+            InputError::Locked(path) => write!(
+                f,
+                "the input is in the vault at {} (set {} to read it)",
+                path.display(),
+                vault::KEY_VAR
+            ),
+            InputError::Vault(path, err) => write!(f, "could not decrypt {}: {err}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// A directory holding one `YYYY/dayNN.txt` file per puzzle.
+#[derive(Debug, Clone)]
+pub struct InputDir(PathBuf);
+
+impl InputDir {
+    pub fn new(dir: impl Into<PathBuf>) -> InputDir {
+        InputDir(dir.into())
+    }
+
+    /// `$AOC_INPUT_DIR` when set, otherwise `input/`.
+    pub fn from_env() -> InputDir {
+        match std::env::var_os(INPUT_DIR_VAR) {
+            Some(dir) if !dir.is_empty() => InputDir::new(dir),
+            _ => InputDir::new(DEFAULT_INPUT_DIR),
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.0
+    }
+
+    pub fn path(&self, year: u32, day: u32) -> PathBuf {
+        self.0.join(format!("{year}/day{day:02}.txt"))
+    }
+
+    /// The plain input, or the vault's copy when there is none.
+    pub fn load(&self, year: u32, day: u32) -> Result<String, InputError> {
+        match read_file(self.path(year, day)) {
+            Err(missing @ InputError::Missing(_)) => vault::load(self, year, day).unwrap_or(Err(missing)),
+            loaded => loaded,
+        }
+    }
+}
+
+pub fn load(year: u32, day: u32) -> Result<String, InputError> {
+    InputDir::from_env().load(year, day)
+}
+
+pub fn read_file(path: impl AsRef<Path>) -> Result<String, InputError> {
+    let path = path.as_ref();
+    match std::fs::read_to_string(path) {
+        Ok(input) => Ok(normalise(&input)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            Err(InputError::Missing(path.to_owned()))
+        }
+        Err(err) => Err(InputError::Io(path.to_owned(), err)),
+    }
+}
+
+pub fn read_stdin() -> Result<String, InputError> {
+    let mut input = String::new();
+    io::stdin()
+        .read_to_string(&mut input)
+        .map_err(|err| InputError::Io(PathBuf::from("<stdin>"), err))?;
+
+    Ok(normalise(&input))
+}
+
+/// Converts CRLF line endings to LF and drops trailing newlines, so parsers
+/// only ever see `\n` separated lines with no empty last line.
+pub fn normalise(input: &str) -> String {
+    input.replace("\r\n", "\n").trim_end_matches('\n').to_owned()
+}
+
+/// Input for a `real` test. Returns `None`, after saying why, when the input
+/// has not been placed, so the test can return early instead of failing. An
+/// input that is in the vault but cannot be read without the key is not
+/// skipped: that would hide the test from anyone who forgot to set it.
+pub fn for_test(year: u32, day: u32) -> Option<String> {
+    match load(year, day) {
+        Ok(input) => Some(input),
+        Err(err @ InputError::Missing(_)) => {
+            // Written to stderr directly, the test harness captures `eprintln!`.
+            let _ = writeln!(io::stderr(), "skipping {year} day {day:02}: {err}");
+            None
+        }
+        Err(err) => panic!("{err}"),
+    }
+}
+
+#[test]
+fn test() {
+    assert_eq!(normalise("a\r\nb\r\n\r\n"), "a\nb");
+    assert_eq!(normalise("a\n\nb\n"), "a\n\nb");
+    assert_eq!(normalise("  a  "), "  a  ");
+
+    let dir = InputDir::new("does-not-exist");
+    assert_eq!(dir.path(2022, 7), Path::new("does-not-exist/2022/day07.txt"));
+    assert!(matches!(dir.load(2022, 7), Err(InputError::Missing(_))));
+}
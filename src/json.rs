@@ -0,0 +1,299 @@
+//! A small JSON reader, for files other tools export.
+
+use crate::error::ParseError;
+
+/// How deeply arrays and objects may nest, so a hostile file cannot
+/// overflow the stack.
+const MAX_DEPTH: usize = 128;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    /// Members in file order.
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    pub fn parse(text: &str) -> Result<Json, ParseError> {
+        let mut parser = Parser { text, pos: 0, depth: 0 };
+
+        let value = parser.value()?;
+        parser.whitespace();
+        if parser.pos < text.len() {
+            return Err(parser.error("expected the end of the document"));
+        }
+
+        Ok(value)
+    }
+
+    /// The member `key` of an object.
+    pub fn get(&self, key: &str) -> Option<&Json> {
+        self.as_object()?.iter().find(|(name, _)| name == key).map(|(_, value)| value)
+    }
+
+    pub fn as_object(&self) -> Option<&[(String, Json)]> {
+        match self {
+            Json::Object(members) => Some(members),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[Json]> {
+        match self {
+            Json::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Json::String(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Json::Number(number) => Some(*number),
+            _ => None,
+        }
+    }
+
+    /// A non-negative whole number, or a string holding one, as some exports
+    /// quote their ids.
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            Json::Number(number) if number.fract() == 0.0 && *number >= 0.0 && *number < u64::MAX as f64 => {
+                Some(*number as u64)
+            }
+            Json::String(text) => text.parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    pos: usize,
+    /// Arrays and objects open around the current position.
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    /// An error about the character at the current position.
+    fn error(&self, message: &str) -> ParseError {
+        let len = self.peek().map_or(0, char::len_utf8);
+        ParseError::at(self.text, &self.rest()[..len], message)
+    }
+
+    fn whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start_matches([' ', '\t', '\n', '\r']).len();
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        self.whitespace();
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char, message: &str) -> Result<(), ParseError> {
+        match self.eat(c) {
+            true => Ok(()),
+            false => Err(self.error(message)),
+        }
+    }
+
+    fn value(&mut self) -> Result<Json, ParseError> {
+        self.whitespace();
+
+        match self.peek() {
+            Some('{' | '[') if self.depth == MAX_DEPTH => Err(self.error("expected less deeply nested values")),
+            Some('{') => self.nested(Self::object),
+            Some('[') => self.nested(Self::array),
+            Some('"') => self.string().map(Json::String),
+            Some('-' | '0'..='9') => self.number(),
+            _ => {
+                for (word, value) in [("null", Json::Null), ("true", Json::Bool(true)), ("false", Json::Bool(false))] {
+                    if self.rest().starts_with(word) {
+                        self.pos += word.len();
+                        return Ok(value);
+                    }
+                }
+                Err(self.error("expected a value"))
+            }
+        }
+    }
+
+    fn nested(&mut self, parse: fn(&mut Self) -> Result<Json, ParseError>) -> Result<Json, ParseError> {
+        self.depth += 1;
+        let value = parse(self);
+        self.depth -= 1;
+        value
+    }
+
+    fn object(&mut self) -> Result<Json, ParseError> {
+        self.pos += 1;
+        let mut members = vec![];
+
+        if self.eat('}') {
+            return Ok(Json::Object(members));
+        }
+        loop {
+            self.whitespace();
+            if self.peek() != Some('"') {
+                return Err(self.error("expected a member name"));
+            }
+            let name = self.string()?;
+            self.expect(':', "expected `:`")?;
+            members.push((name, self.value()?));
+
+            if self.eat('}') {
+                return Ok(Json::Object(members));
+            }
+            self.expect(',', "expected `,` or `}`")?;
+        }
+    }
+
+    fn array(&mut self) -> Result<Json, ParseError> {
+        self.pos += 1;
+        let mut items = vec![];
+
+        if self.eat(']') {
+            return Ok(Json::Array(items));
+        }
+        loop {
+            items.push(self.value()?);
+
+            if self.eat(']') {
+                return Ok(Json::Array(items));
+            }
+            self.expect(',', "expected `,` or `]`")?;
+        }
+    }
+
+    fn string(&mut self) -> Result<String, ParseError> {
+        self.pos += 1;
+        let mut string = String::new();
+
+        loop {
+            let Some(c) = self.peek() else {
+                return Err(self.error("expected the end of the string"));
+            };
+            match c {
+                '"' => {
+                    self.pos += 1;
+                    return Ok(string);
+                }
+                '\\' => {
+                    self.pos += 1;
+                    string.push(self.escape()?);
+                }
+                c if c < ' ' => return Err(self.error("expected an escaped control character")),
+                c => {
+                    string.push(c);
+                    self.pos += c.len_utf8();
+                }
+            }
+        }
+    }
+
+    fn escape(&mut self) -> Result<char, ParseError> {
+        let c = match self.peek() {
+            Some('"') => '"',
+            Some('\\') => '\\',
+            Some('/') => '/',
+            Some('b') => '\u{8}',
+            Some('f') => '\u{c}',
+            Some('n') => '\n',
+            Some('r') => '\r',
+            Some('t') => '\t',
+            Some('u') => {
+                self.pos += 1;
+                let high = self.hex4()?;
+                if !(0xD800..0xDC00).contains(&high) {
+                    return char::from_u32(high).ok_or_else(|| self.error("expected a character"));
+                }
+                if !self.rest().starts_with("\\u") {
+                    return Err(self.error("expected the second half of a surrogate pair"));
+                }
+                self.pos += 2;
+                let low = self.hex4()?;
+                return char::from_u32(0x10000 + ((high - 0xD800) << 10) + (low.wrapping_sub(0xDC00) & 0x3FF))
+                    .filter(|_| (0xDC00..0xE000).contains(&low))
+                    .ok_or_else(|| self.error("expected the second half of a surrogate pair"));
+            }
+            _ => return Err(self.error("expected an escape sequence")),
+        };
+        self.pos += 1;
+        Ok(c)
+    }
+
+    fn hex4(&mut self) -> Result<u32, ParseError> {
+        let digits = self.rest().get(..4).filter(|digits| digits.chars().all(|c| c.is_ascii_hexdigit()));
+        let value = digits
+            .and_then(|digits| u32::from_str_radix(digits, 16).ok())
+            .ok_or_else(|| self.error("expected four hex digits"))?;
+        self.pos += 4;
+        Ok(value)
+    }
+
+    fn number(&mut self) -> Result<Json, ParseError> {
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| !matches!(c, '0'..='9' | '-' | '+' | '.' | 'e' | 'E'))
+            .unwrap_or(rest.len());
+        let token = &rest[..len];
+
+        let number = token
+            .parse()
+            .ok()
+            .filter(|_| !token.starts_with("-.") && !token.trim_start_matches('-').starts_with('.'))
+            .ok_or_else(|| ParseError::at(self.text, token, "expected a number"))?;
+        self.pos += len;
+        Ok(Json::Number(number))
+    }
+}
+
+#[test]
+fn test() {
+    let json = Json::parse(r#" {"a": [1, -2.5e1, true, null], "b": {"c": "x\"\u00e9\ud83d\ude00\n"}, "id": "42"} "#).unwrap();
+    assert_eq!(
+        json.get("a").unwrap().as_array().unwrap(),
+        [Json::Number(1.0), Json::Number(-25.0), Json::Bool(true), Json::Null]
+    );
+    assert_eq!(json.get("b").and_then(|b| b.get("c")).and_then(Json::as_str), Some("x\"é😀\n"));
+    assert_eq!(json.get("id").and_then(Json::as_u64), Some(42));
+    assert_eq!(Json::Number(1.5).as_u64(), None);
+    assert_eq!(Json::parse("[]").unwrap(), Json::Array(vec![]));
+
+    let err = Json::parse("{\"a\": 1,\n \"b\" 2}").unwrap_err();
+    assert_eq!(err.to_string(), "line 2, column 6: expected `:`, found `2`");
+    let err = Json::parse("[1, 2").unwrap_err();
+    assert_eq!(err.to_string(), "line 1, column 6: expected `,` or `]`, found nothing");
+    assert!(Json::parse("\"open").is_err());
+    assert!(Json::parse("1 2").is_err());
+    assert!(Json::parse("nul").is_err());
+    assert!(Json::parse("\"\\ud83d\"").is_err());
+
+    let nested = format!("{}1{}", "[".repeat(MAX_DEPTH), "]".repeat(MAX_DEPTH));
+    assert!(Json::parse(&nested).is_ok());
+    let err = Json::parse(&"[".repeat(200_000)).unwrap_err();
+    assert_eq!(err.to_string(), "line 1, column 129: expected less deeply nested values, found `[`");
+}
@@ -0,0 +1,270 @@
+//! Private leaderboards, as exported from the site's leaderboard page.
+//!
+//! The export is JSON with an `event` year and a `members` object; each
+//! member's `completion_day_level` maps day and part to the Unix time the
+//! star was earned in `get_star_ts`.
+
+use std::{collections::BTreeMap, fmt, fmt::Write, time::Duration};
+
+use crate::{error::ParseError, fetch::unlocks_at, json::Json};
+
+#[derive(Debug)]
+pub enum LeaderboardError {
+    Json(ParseError),
+    /// Valid JSON that is not a leaderboard, with the path to the problem.
+    Shape(String),
+}
+
+impl fmt::Display for LeaderboardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LeaderboardError::Json(err) => write!(f, "invalid JSON: {err}"),
+            LeaderboardError::Shape(message) => write!(f, "not a leaderboard: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for LeaderboardError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    /// The local score the site reported.
+    pub local_score: u64,
+    /// When each star was earned, in seconds since the Unix epoch, by day
+    /// and part.
+    pub stars: BTreeMap<(u32, u32), u64>,
+}
+
+impl Member {
+    /// The name the site shows, which for members without one is their id.
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    /// How long after the puzzle unlocked part `part` of `day` was solved.
+    pub fn solve_time(&self, year: u32, day: u32, part: u32) -> Option<Duration> {
+        let earned = *self.stars.get(&(day, part))?;
+        let unlocked = unlocks_at(year, day).duration_since(std::time::UNIX_EPOCH).ok()?;
+
+        Some(Duration::from_secs(earned).saturating_sub(unlocked))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Leaderboard {
+    pub year: u32,
+    /// Ordered by id.
+    pub members: Vec<Member>,
+}
+
+impl std::str::FromStr for Leaderboard {
+    type Err = LeaderboardError;
+
+    fn from_str(text: &str) -> Result<Leaderboard, LeaderboardError> {
+        let json = Json::parse(text).map_err(LeaderboardError::Json)?;
+        let shape = |path: &str, expected: &str| LeaderboardError::Shape(format!("expected {expected} at `{path}`"));
+
+        let year = json
+            .get("event")
+            .and_then(Json::as_u64)
+            .and_then(|year| u32::try_from(year).ok())
+            .ok_or_else(|| shape("event", "a year"))?;
+        let members_json = json.get("members").and_then(Json::as_object).ok_or_else(|| shape("members", "an object"))?;
+
+        let mut members = vec![];
+        for (key, member) in members_json {
+            let path = format!("members.{key}");
+            let id = member.get("id").and_then(Json::as_u64).ok_or_else(|| shape(&format!("{path}.id"), "a number"))?;
+            let name = match member.get("name") {
+                None | Some(Json::Null) => None,
+                Some(name) => Some(name.as_str().ok_or_else(|| shape(&format!("{path}.name"), "a string"))?.to_owned()),
+            };
+            let local_score = member
+                .get("local_score")
+                .and_then(Json::as_u64)
+                .ok_or_else(|| shape(&format!("{path}.local_score"), "a number"))?;
+
+            let mut stars = BTreeMap::new();
+            let days = member
+                .get("completion_day_level")
+                .and_then(Json::as_object)
+                .ok_or_else(|| shape(&format!("{path}.completion_day_level"), "an object"))?;
+            for (day, parts) in days {
+                let day_path = format!("{path}.completion_day_level.{day}");
+                let day = day.parse().map_err(|_| shape(&day_path, "a day number"))?;
+                for (part, star) in parts.as_object().ok_or_else(|| shape(&day_path, "an object"))? {
+                    let star_path = format!("{day_path}.{part}");
+                    let part = part.parse().map_err(|_| shape(&star_path, "a part number"))?;
+                    let earned = star
+                        .get("get_star_ts")
+                        .and_then(Json::as_u64)
+                        .ok_or_else(|| shape(&format!("{star_path}.get_star_ts"), "a timestamp"))?;
+                    stars.insert((day, part), earned);
+                }
+            }
+
+            members.push(Member { id, name, local_score, stars });
+        }
+        members.sort_by_key(|member| member.id);
+
+        Ok(Leaderboard { year, members })
+    }
+}
+
+impl Leaderboard {
+    /// Local scores worked out from the star times: on each star, the first
+    /// member to earn it gets as many points as there are members, the next
+    /// one less, and so on. Highest first; ties go to whoever got their last
+    /// star earlier.
+    pub fn ranking(&self) -> Vec<(&Member, u64)> {
+        let mut scores = vec![0; self.members.len()];
+
+        for star in self.stars() {
+            let mut earned: Vec<(u64, usize)> = self
+                .members
+                .iter()
+                .enumerate()
+                .filter_map(|(i, member)| Some((*member.stars.get(&star)?, i)))
+                .collect();
+            earned.sort();
+            for (rank, (_, i)) in earned.into_iter().enumerate() {
+                scores[i] += (self.members.len() - rank) as u64;
+            }
+        }
+
+        let mut ranking: Vec<(&Member, u64)> = self.members.iter().zip(scores).collect();
+        ranking.sort_by_key(|(member, score)| (std::cmp::Reverse(*score), member.stars.values().max().copied(), member.id));
+        ranking
+    }
+
+    /// Every star anyone has, by day and part.
+    fn stars(&self) -> Vec<(u32, u32)> {
+        let mut stars: Vec<(u32, u32)> = self.members.iter().flat_map(|member| member.stars.keys().copied()).collect();
+        stars.sort();
+        stars.dedup();
+        stars
+    }
+
+    /// Days anyone has a star on, or that are `solved`.
+    pub fn days(&self, solved: &[(u32, &str)]) -> Vec<u32> {
+        let mut days: Vec<u32> = self.stars().into_iter().map(|(day, _)| day).collect();
+        days.extend(solved.iter().map(|&(day, _)| day));
+        days.sort();
+        days.dedup();
+        days
+    }
+
+    /// The ranking, then for each day how long every member who solved it
+    /// took, fastest full solve first, and how long part 2 took after part 1.
+    /// `solved` holds the number and title of each day this crate solves.
+    pub fn report(&self, solved: &[(u32, &str)]) -> String {
+        let width = self.members.iter().map(|member| member.display_name().chars().count()).max().unwrap_or(0);
+        let mut report = format!("{} private leaderboard, {} members\n\nLocal score\n", self.year, self.members.len());
+
+        for (rank, (member, score)) in self.ranking().into_iter().enumerate() {
+            writeln!(
+                report,
+                "  {:>3}. {:<width$}  {score:>5}  {} stars",
+                rank + 1,
+                member.display_name(),
+                member.stars.len()
+            )
+            .unwrap();
+        }
+
+        for day in self.days(solved) {
+            match solved.iter().find(|&&(number, _)| number == day) {
+                Some((_, title)) => writeln!(report, "\nDay {day:02}: {title}"),
+                None => writeln!(report, "\nDay {day:02}: not solved in this crate"),
+            }
+            .unwrap();
+
+            let mut times: Vec<(&Member, Option<Duration>, Option<Duration>)> = self
+                .members
+                .iter()
+                .map(|member| (member, member.solve_time(self.year, day, 1), member.solve_time(self.year, day, 2)))
+                .filter(|(_, part1, _)| part1.is_some())
+                .collect();
+            times.sort_by_key(|(member, part1, part2)| (part2.is_none(), *part2, *part1, member.id));
+            if times.is_empty() {
+                report.push_str("  no stars yet\n");
+            }
+
+            for (member, part1, part2) in times {
+                write!(report, "  {:<width$}  part 1 {:>9}", member.display_name(), elapsed(part1.unwrap())).unwrap();
+                if let (Some(part1), Some(part2)) = (part1, part2) {
+                    write!(report, "  part 2 {:>9}  (+{})", elapsed(part2), elapsed(part2.saturating_sub(part1))).unwrap();
+                }
+                report.push('\n');
+            }
+        }
+
+        report
+    }
+}
+
+/// `h:mm:ss`, with as many hours as it takes.
+fn elapsed(time: Duration) -> String {
+    let seconds = time.as_secs();
+    format!("{}:{:02}:{:02}", seconds / 3600, seconds / 60 % 60, seconds % 60)
+}
+
+#[test]
+fn test() {
+    // 2022-12-01 05:00 UTC, when day 1 unlocked.
+    const DAY1: u64 = 1669870800;
+    let export = format!(
+        r#"{{"event": "2022", "owner_id": 1, "members": {{
+            "1": {{"id": 1, "name": "Ada", "stars": 3, "local_score": 8, "global_score": 0, "last_star_ts": {},
+                "completion_day_level": {{
+                    "1": {{"1": {{"get_star_ts": {}, "star_index": 1}}, "2": {{"get_star_ts": {}, "star_index": 4}}}},
+                    "2": {{"1": {{"get_star_ts": {}, "star_index": 9}}}}}}}},
+            "7": {{"id": 7, "name": null, "stars": 2, "local_score": 5, "global_score": 0, "last_star_ts": {},
+                "completion_day_level": {{"1": {{"1": {{"get_star_ts": {}, "star_index": 2}}, "2": {{"get_star_ts": {}, "star_index": 3}}}}}}}},
+            "3": {{"id": 3, "name": "Bo", "stars": 1, "local_score": 3, "global_score": 0, "last_star_ts": {},
+                "completion_day_level": {{"15": {{"1": {{"get_star_ts": {}, "star_index": 10}}}}}}}}
+        }}}}"#,
+        DAY1 + 86400 + 3600,
+        DAY1 + 300,
+        DAY1 + 460,
+        DAY1 + 86400 + 3600,
+        DAY1 + 400,
+        DAY1 + 400,
+        DAY1 + 420,
+        DAY1 + 14 * 86400 + 59,
+        DAY1 + 14 * 86400 + 59,
+    );
+
+    let board: Leaderboard = export.parse().unwrap();
+    assert_eq!(board.year, 2022);
+    assert_eq!(board.members.iter().map(|member| member.id).collect::<Vec<_>>(), [1, 3, 7]);
+    assert_eq!(board.members[2].display_name(), "(anonymous user #7)");
+    assert_eq!(board.members[0].solve_time(2022, 1, 2), Some(Duration::from_secs(460)));
+
+    let ranking: Vec<(u64, u64)> = board.ranking().into_iter().map(|(member, score)| (member.id, score)).collect();
+    assert_eq!(ranking, [(1, 8), (7, 5), (3, 3)]);
+    assert!(board.ranking().iter().all(|(member, score)| member.local_score == *score));
+
+    let report = board.report(&[(1, "Calorie Counting"), (2, "Rock Paper Scissors"), (3, "Rucksack Reorganization")]);
+    assert!(report.starts_with("2022 private leaderboard, 3 members\n\nLocal score\n    1. Ada                      8  3 stars\n"));
+    assert!(report.contains(
+        "\nDay 01: Calorie Counting\n  (anonymous user #7)  part 1   0:06:40  part 2   0:07:00  (+0:00:20)\n  Ada                  part 1   0:05:00  part 2   0:07:40  (+0:02:40)\n"
+    ));
+    assert!(report.contains("\nDay 02: Rock Paper Scissors\n  Ada                  part 1   1:00:00\n"));
+    assert!(report.ends_with(
+        "\nDay 03: Rucksack Reorganization\n  no stars yet\n\nDay 15: not solved in this crate\n  Bo                   part 1   0:00:59\n"
+    ));
+
+    let err = r#"{"event": 2022, "members": {"1": {"id": 1, "local_score": 0, "completion_day_level": {"x": {}}}}}"#
+        .parse::<Leaderboard>()
+        .unwrap_err();
+    assert_eq!(err.to_string(), "not a leaderboard: expected a day number at `members.1.completion_day_level.x`");
+    assert!(matches!("{".parse::<Leaderboard>(), Err(LeaderboardError::Json(_))));
+    let err = r#"{"event": 4294967298, "members": {}}"#.parse::<Leaderboard>().unwrap_err();
+    assert_eq!(err.to_string(), "not a leaderboard: expected a year at `event`");
+}
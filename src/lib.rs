@@ -1,17 +1,107 @@
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-
-
-mod pos2d;
+//! Advent of Code solutions, 2022 so far.
+//!
+//! Every day implements [`Solution`] and is listed in [`DAYS`] under its
+//! year; look one up with [`day`] to parse an input and solve either part
+//! without knowing its types. Each year's modules live in their own `yYYYY`
+//! module, with inputs under `input/YYYY/`. Parsing never panics on bad
+//! input: it returns a [`ParseError`] with the line and column at fault.
+//! Pieces that are useful beyond their own puzzle are re-exported here:
+//! [`Pos2d`], 2022 day07's directory [`Node`] tree, day12's [`Heightmap`]
+//! with its shortest path search, and day13's packet [`Value`] with its
+//! ordering.
+
+pub mod y2022;
+
+pub mod answer;
+pub mod answers;
+pub mod bench;
+pub mod cache;
+pub mod cancel;
+pub mod config;
+pub mod crosscheck;
+pub mod error;
+pub mod examples;
+pub mod fetch;
+pub mod http;
+pub mod input;
+pub mod json;
+pub mod leaderboard;
+pub mod log;
+pub mod memory;
+#[cfg(test)]
+mod mock;
+pub mod parallel;
+pub mod pos2d;
+pub mod puzzle;
+pub mod report;
+pub mod scaffold;
+pub mod solution;
+pub mod stream;
+pub mod submit;
+pub mod vault;
+pub mod watch;
+
+pub use answer::Answer;
+pub use error::{Error, ParseError, Result};
+pub use pos2d::{Direction, Pos2d};
+pub use solution::{Day, Solution};
+pub use y2022::day07::Node;
+pub use y2022::day12::Heightmap;
+pub use y2022::day13::Value;
+
+/// Every solved day, ordered by year and day.
+pub static DAYS: &[Day] = &[
+    Day::new::<y2022::day01::Day01>(2022, 1, "Calorie Counting"),
+    Day::new::<y2022::day02::Day02>(2022, 2, "Rock Paper Scissors"),
+    Day::new::<y2022::day03::Day03>(2022, 3, "Rucksack Reorganization"),
+    Day::new::<y2022::day04::Day04>(2022, 4, "Camp Cleanup"),
+    Day::new::<y2022::day05::Day05>(2022, 5, "Supply Stacks"),
+    Day::new::<y2022::day06::Day06>(2022, 6, "Tuning Trouble"),
+    Day::new::<y2022::day07::Day07>(2022, 7, "No Space Left On Device"),
+    Day::new::<y2022::day08::Day08>(2022, 8, "Treetop Tree House"),
+    Day::new::<y2022::day09::Day09>(2022, 9, "Rope Bridge"),
+    Day::new::<y2022::day10::Day10>(2022, 10, "Cathode-Ray Tube"),
+    Day::new::<y2022::day11::Day11>(2022, 11, "Monkey in the Middle"),
+    Day::new::<y2022::day12::Day12>(2022, 12, "Hill Climbing Algorithm"),
+    Day::new::<y2022::day13::Day13>(2022, 13, "Distress Signal"),
+    Day::new::<y2022::day14::Day14>(2022, 14, "Regolith Reservoir"),
+];
+
+/// Looks up a day by its year and number, 1-25.
+pub fn day(year: u32, day: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.year == year && d.day == day)
+}
+
+/// The days of one year, in order.
+pub fn days(year: u32) -> impl Iterator<Item = &'static Day> {
+    DAYS.iter().filter(move |d| d.year == year)
+}
+
+/// Every year with at least one solved day, oldest first.
+pub fn years() -> Vec<u32> {
+    let mut years: Vec<u32> = DAYS.iter().map(|d| d.year).collect();
+    years.dedup();
+    years
+}
+
+#[test]
+fn registry() {
+    assert!(DAYS.windows(2).all(|pair| (pair[0].year, pair[0].day) < (pair[1].year, pair[1].day)));
+    assert!(years().contains(&2022));
+    assert!(days(2022).count() >= 14);
+    for year in years() {
+        for (d, number) in days(year).zip(1..) {
+            assert_eq!(d.day, number);
+            assert!(std::ptr::eq(day(year, number).unwrap(), d));
+        }
+        assert!(day(year, days(year).count() as u32 + 1).is_none());
+    }
+    assert!(day(2021, 1).is_none());
+
+    let day02 = day(2022, 2).unwrap();
+    let parsed = day02.parse("A Y\nB X\nC Z").unwrap();
+    assert_eq!(day02.part1(&parsed).unwrap(), Answer::Int(15));
+    assert_eq!(day02.part(2, &parsed).unwrap().unwrap(), Answer::Int(12));
+    assert!(day02.parse("A Y\nB Q").is_err());
+    assert!(day02.part(3, &parsed).is_none());
+}
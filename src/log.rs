@@ -0,0 +1,193 @@
+//! Leveled diagnostics for solvers, written to stderr and off by default.
+//!
+//! Levels are set per day with a spec such as `debug`, `9=trace` or
+//! `info,12=debug,2022/day09=trace`: a bare level applies to every day,
+//! `DAY=LEVEL` overrides it for that day of every year and `YEAR/DAY=LEVEL`
+//! for that day of one year. The spec comes from `AOC_LOG`, or from
+//! `configure`, which the binary calls for `--log`.
+
+use std::{
+    collections::BTreeMap,
+    fmt,
+    str::FromStr,
+    sync::{Once, RwLock},
+};
+
+pub const LOG_VAR: &str = "AOC_LOG";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error = 1,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(level: &str) -> Result<Self, Self::Err> {
+        match level {
+            "error" => Ok(Level::Error),
+            "warn" => Ok(Level::Warn),
+            "info" => Ok(Level::Info),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            _ => Err(format!("unknown log level `{level}`, expected error, warn, info, debug or trace")),
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Level::Error => "error",
+            Level::Warn => "warn",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        };
+        f.write_str(name)
+    }
+}
+
+const OFF: u8 = 0;
+
+/// The most verbose level enabled, as set by a spec.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Levels {
+    /// For days without a level of their own.
+    default: Option<u8>,
+    /// By year, `None` for every year, and day.
+    days: BTreeMap<(Option<u32>, u32), u8>,
+}
+
+impl Levels {
+    fn get(&self, year: u32, day: u32) -> Option<u8> {
+        let days = &self.days;
+        days.get(&(Some(year), day)).or_else(|| days.get(&(None, day))).copied().or(self.default)
+    }
+}
+
+static LEVELS: RwLock<Levels> = RwLock::new(Levels { default: None, days: BTreeMap::new() });
+static FROM_ENV: Once = Once::new();
+
+/// Replaces the current levels with `spec`. Leaves them untouched if the
+/// spec is invalid.
+pub fn configure(spec: &str) -> Result<(), String> {
+    let levels = parse_spec(spec)?;
+
+    FROM_ENV.call_once(|| {});
+    store(levels);
+
+    Ok(())
+}
+
+pub fn enabled(year: u32, day: u32, level: Level) -> bool {
+    FROM_ENV.call_once(|| {
+        if let Ok(spec) = std::env::var(LOG_VAR) {
+            match parse_spec(&spec) {
+                Ok(levels) => store(levels),
+                Err(err) => eprintln!("ignoring {LOG_VAR}: {err}"),
+            }
+        }
+    });
+
+    let max = LEVELS.read().unwrap_or_else(|poisoned| poisoned.into_inner()).get(year, day);
+    max.is_some_and(|max| max != OFF && level as u8 <= max)
+}
+
+/// Writes a message without checking the level; use the `log!`, `debug!`
+/// and `trace!` macros instead.
+#[doc(hidden)]
+pub fn write(year: u32, day: u32, level: Level, message: fmt::Arguments) {
+    eprintln!("[{year} day{day:02} {level}] {message}");
+}
+
+fn store(levels: Levels) {
+    *LEVELS.write().unwrap_or_else(|poisoned| poisoned.into_inner()) = levels;
+}
+
+fn parse_spec(spec: &str) -> Result<Levels, String> {
+    let mut levels = Levels::default();
+
+    for item in spec.split(',').map(str::trim).filter(|item| !item.is_empty()) {
+        let (target, level) = match item.split_once('=') {
+            Some((target, level)) => (Some(target), level),
+            None => (None, item),
+        };
+        let level = match level {
+            "off" => OFF,
+            level => level.parse::<Level>()? as u8,
+        };
+
+        let Some(target) = target else {
+            levels.default = Some(level);
+            continue;
+        };
+        let (year, day) = match target.split_once('/') {
+            Some((year, day)) => {
+                let year = year.parse().map_err(|_| format!("invalid year `{year}` in log spec"))?;
+                (Some(year), day)
+            }
+            None => (None, target),
+        };
+        let number: u32 = day
+            .strip_prefix("day")
+            .unwrap_or(day)
+            .parse()
+            .map_err(|_| format!("invalid day `{day}` in log spec"))?;
+        if !(1..=25).contains(&number) {
+            return Err(format!("invalid day `{day}` in log spec"));
+        }
+        levels.days.insert((year, number), level);
+    }
+
+    Ok(levels)
+}
+
+/// Logs a message for a day of a year at a level:
+/// `log!(2022, 9, Level::Debug, "...", ...)`. The message is only formatted
+/// when the level is enabled.
+#[macro_export]
+macro_rules! log {
+    ($year:expr, $day:expr, $level:expr, $($arg:tt)+) => {
+        if $crate::log::enabled($year, $day, $level) {
+            $crate::log::write($year, $day, $level, format_args!($($arg)+));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($year:expr, $day:expr, $($arg:tt)+) => {
+        $crate::log!($year, $day, $crate::log::Level::Debug, $($arg)+)
+    };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($year:expr, $day:expr, $($arg:tt)+) => {
+        $crate::log!($year, $day, $crate::log::Level::Trace, $($arg)+)
+    };
+}
+
+#[test]
+fn test() {
+    let levels = parse_spec("info, day09=trace,12=off,2021/12=debug").unwrap();
+    assert_eq!(levels.get(2022, 1), Some(Level::Info as u8));
+    assert_eq!(levels.get(2021, 9), Some(Level::Trace as u8));
+    assert_eq!(levels.get(2022, 12), Some(OFF));
+    assert_eq!(levels.get(2021, 12), Some(Level::Debug as u8));
+    assert_eq!(parse_spec("2022/day09=trace").unwrap().get(2021, 9), None);
+
+    assert!(parse_spec("9=loud").is_err());
+    assert!(parse_spec("26=debug").is_err());
+    assert!(parse_spec("x=debug").is_err());
+    assert!(parse_spec("y2k/9=debug").is_err());
+    assert_eq!(parse_spec("").unwrap(), Levels::default());
+
+    assert!("verbose".parse::<Level>().is_err());
+    assert!(Level::Trace > Level::Debug);
+}
@@ -0,0 +1,201 @@
+//! Heap accounting for solvers.
+//!
+//! [`Counting`] wraps the system allocator and, once [`enable`]d, counts
+//! every allocation made on each thread. A binary opts in by installing it:
+//!
+//! ```ignore
+//! #[global_allocator]
+//! static ALLOCATOR: aoc2022::memory::Counting = aoc2022::memory::Counting;
+//! ```
+//!
+//! Counts are per thread, so [`measure`] only sees what its closure allocates
+//! on the calling thread, which is where solvers run.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    fmt,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+/// Heap use of one measured call. `peak` is the most memory the call held
+/// at once, on top of what was already live when it started.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Usage {
+    pub allocations: u64,
+    pub bytes: u64,
+    pub peak: u64,
+}
+
+impl fmt::Display for Usage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} allocations, {} allocated, {} peak", self.allocations, Bytes(self.bytes), Bytes(self.peak))
+    }
+}
+
+/// A byte count with a binary unit, to 3 significant figures.
+pub struct Bytes(pub u64);
+
+impl fmt::Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bytes = self.0 as f64;
+        let (value, unit) = match bytes {
+            b if b < 1024.0 => return write!(f, "{} B", self.0),
+            b if b < 1024.0 * 1024.0 => (b / 1024.0, "KiB"),
+            b if b < 1024.0 * 1024.0 * 1024.0 => (b / (1024.0 * 1024.0), "MiB"),
+            b => (b / (1024.0 * 1024.0 * 1024.0), "GiB"),
+        };
+        let decimals = match value {
+            v if v < 10.0 => 2,
+            v if v < 100.0 => 1,
+            _ => 0,
+        };
+
+        write!(f, "{value:.decimals$} {unit}")
+    }
+}
+
+/// The system allocator, counting allocations while counting is enabled.
+pub struct Counting;
+
+#[derive(Clone, Copy)]
+struct Counts {
+    allocations: u64,
+    bytes: u64,
+    /// Can go negative: a thread may free memory another thread allocated.
+    live: i64,
+    peak: i64,
+}
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static COUNTS: Cell<Counts> = const { Cell::new(Counts { allocations: 0, bytes: 0, live: 0, peak: 0 }) };
+}
+
+fn count(allocated: usize, freed: usize) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+
+    // `try_with` fails while the thread is being torn down; those
+    // allocations go uncounted.
+    let _ = COUNTS.try_with(|counts| {
+        let mut c = counts.get();
+        if allocated > 0 {
+            c.allocations += 1;
+            c.bytes += allocated as u64;
+        }
+        c.live += allocated as i64 - freed as i64;
+        c.peak = c.peak.max(c.live);
+        counts.set(c);
+    });
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            count(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            count(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        count(0, layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            count(new_size, layout.size());
+        }
+        new_ptr
+    }
+}
+
+/// Starts counting. Returns false, leaving counting off, if `Counting` is
+/// not the global allocator, since nothing would be counted.
+pub fn enable() -> bool {
+    ENABLED.store(true, Ordering::Relaxed);
+
+    let before = COUNTS.with(Cell::get).allocations;
+    drop(std::hint::black_box(Box::new(0u8)));
+    let installed = COUNTS.with(Cell::get).allocations > before;
+
+    ENABLED.store(installed, Ordering::Relaxed);
+    installed
+}
+
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Runs `f`, and measures what it allocates if counting is enabled. Nested
+/// calls are fine, but the outer one's peak then only counts from the start
+/// of the inner one.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Usage>) {
+    if !enabled() {
+        return (f(), None);
+    }
+
+    let start = COUNTS.with(|counts| {
+        let mut c = counts.get();
+        c.peak = c.live;
+        counts.set(c);
+        c
+    });
+    let result = f();
+    let end = COUNTS.with(Cell::get);
+
+    let usage = Usage {
+        allocations: end.allocations - start.allocations,
+        bytes: end.bytes - start.bytes,
+        peak: (end.peak - start.live).max(0) as u64,
+    };
+    (result, Some(usage))
+}
+
+#[cfg(test)]
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+#[test]
+fn test() {
+    assert!(enable());
+
+    let (sum, usage) = measure(|| {
+        let big = std::hint::black_box(vec![1u64; 1000]);
+        let small = std::hint::black_box(vec![1u64; 10]);
+        big.iter().sum::<u64>() + small.iter().sum::<u64>()
+    });
+    assert_eq!(sum, 1010);
+    assert_eq!(usage, Some(Usage { allocations: 2, bytes: 8080, peak: 8080 }));
+
+    let (_, usage) = measure(|| {
+        drop(vec![0u8; 100]);
+        drop(vec![0u8; 50]);
+    });
+    assert_eq!(usage.unwrap().peak, 100);
+
+    let day14 = crate::day(2022, 14).unwrap();
+    let (parsed, usage) = measure(|| day14.parse(include_str!("../input/2022/examples/day14-1.txt")));
+    assert!(parsed.is_ok() && usage.unwrap().allocations > 0);
+
+    assert_eq!(Bytes(512).to_string(), "512 B");
+    assert_eq!(Bytes(1536).to_string(), "1.50 KiB");
+    assert_eq!(Bytes(300 << 20).to_string(), "300 MiB");
+    assert_eq!(
+        Usage { allocations: 3, bytes: 2048, peak: 1024 }.to_string(),
+        "3 allocations, 2.00 KiB allocated, 1.00 KiB peak"
+    );
+}
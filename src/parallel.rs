@@ -0,0 +1,118 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    answers::Answers,
+    report::{self, Record},
+    Day,
+};
+
+/// Aggregate timing of a parallel run. `cpu` is the time spent inside jobs
+/// summed over all workers; std has no per-thread CPU clock, so waiting
+/// inside a job counts too.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Timing {
+    pub wall: Duration,
+    pub cpu: Duration,
+    pub threads: usize,
+}
+
+/// Calls `f` on every item using up to `threads` scoped worker threads that
+/// take the next item as soon as they are free. Results come back in the
+/// order of `items`, whatever order they finished in, with the number of
+/// threads started: never more than there are items.
+pub fn map<T: Sync, R: Send>(items: &[T], threads: usize, f: impl Fn(&T) -> R + Sync) -> (Vec<R>, usize) {
+    let threads = threads.clamp(1, items.len().max(1));
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..threads {
+            let sender = sender.clone();
+            let (next, f) = (&next, &f);
+            scope.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(i) else {
+                    break;
+                };
+                if sender.send((i, f(item))).is_err() {
+                    break;
+                }
+            });
+        }
+    });
+    drop(sender);
+
+    let mut results: Vec<Option<R>> = items.iter().map(|_| None).collect();
+    for (i, result) in receiver {
+        results[i] = Some(result);
+    }
+    let results = results.into_iter().map(|result| result.expect("every job ran")).collect();
+    (results, threads)
+}
+
+/// Parses every input, then solves every requested part, each step as its
+/// own job on the pool. Records come back ordered as `work`, then by part.
+pub fn run(work: &[(&Day, &str)], parts: &[u32], answers: &Answers, threads: usize) -> (Vec<Record>, Timing) {
+    let start = Instant::now();
+
+    let (parsed, parse_threads) = map(work, threads, |(day, input)| report::parse(day, input));
+
+    let jobs: Vec<(usize, u32)> = (0..work.len())
+        .flat_map(|i| parts.iter().map(move |&part| (i, part)))
+        .collect();
+    let (solved, solve_threads) = map(&jobs, threads, |&(i, part)| {
+        let (day, input) = work[i];
+        report::run_part(day, input, &parsed[i], part, answers)
+    });
+    let records: Vec<Record> = solved.into_iter().flatten().collect();
+
+    let cpu = parsed.iter().map(|parsed| parsed.time).sum::<Duration>()
+        + records.iter().map(|record| record.solve_time).sum::<Duration>();
+
+    let timing = Timing {
+        wall: start.elapsed(),
+        cpu,
+        threads: parse_threads.max(solve_threads),
+    };
+
+    (records, timing)
+}
+
+#[test]
+fn test() {
+    let (squares, threads) = map(&(0..100).collect::<Vec<u64>>(), 8, |n| {
+        thread::sleep(Duration::from_micros(100 - n));
+        n * n
+    });
+    assert_eq!(squares, (0..100).map(|n| n * n).collect::<Vec<u64>>());
+    assert_eq!(threads, 8);
+    assert_eq!(map(&[] as &[u32], 4, |n| *n), (vec![], 1));
+    assert_eq!(map(&[1, 2], 4, |n| n + 1), (vec![2, 3], 2));
+
+    let (day01, day02) = (crate::day(2022, 1).unwrap(), crate::day(2022, 2).unwrap());
+    let work = [(day02, "A Y\nB X\nC Z"), (day01, "1\n\nx"), (day01, "1\n2\n\n3\n\n4")];
+
+    let (records, timing) = run(&work, &[1, 2], &Answers::default(), 3);
+    let summary: Vec<(u32, u32, Result<&str, bool>)> = records
+        .iter()
+        .map(|r| (r.day, r.part, r.answer.as_deref().map_err(|err| err.starts_with("parse error"))))
+        .collect();
+    assert_eq!(
+        summary,
+        [(2, 1, Ok("15")), (2, 2, Ok("12")), (1, 1, Err(true)), (1, 2, Err(true)), (1, 1, Ok("4")), (1, 2, Ok("10"))]
+    );
+    assert_eq!(timing.threads, 3);
+    assert!(timing.wall > Duration::ZERO);
+
+    // Two parts of one day are two jobs, so only two of the eight threads start.
+    let (records, timing) = run(&work[..1], &[1, 2], &Answers::default(), 8);
+    assert_eq!(records.len(), 2);
+    assert_eq!(timing.threads, 2);
+}
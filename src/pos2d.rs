@@ -1,11 +1,15 @@
-use std::fs::DirBuilder;
+use std::str::FromStr;
 
-#[derive(Clone, Copy, Eq, Hash, PartialEq, Debug, Ord, PartialOrd)]
+use crate::error::{self, ParseError};
+
+/// A grid position, with `y` growing downwards as in the puzzle drawings.
+#[derive(Clone, Copy, Eq, Hash, PartialEq, Debug, Ord, PartialOrd, Default)]
 pub struct Pos2d {
     pub x: i32,
     pub y: i32,
 }
 
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
 pub enum Direction {
     Up,
     Left,
@@ -15,6 +19,10 @@ pub enum Direction {
 }
 
 impl Pos2d {
+    pub const fn new(x: i32, y: i32) -> Pos2d {
+        Pos2d { x, y }
+    }
+
     pub fn up(self) -> Pos2d {
         Pos2d {
             x: self.x,
@@ -49,42 +57,51 @@ impl Pos2d {
         }
     }
 
-    pub fn cmp_no_diagonal(&self, other: &Pos2d) -> Direction {
-        if self.x < other.x {
-            assert_eq!(self.y, other.y);
+    /// The direction to step in to get from `self` towards `other`, or
+    /// `None` if `other` is not on the same row or column.
+    pub fn cmp_no_diagonal(&self, other: &Pos2d) -> Option<Direction> {
+        if self.x != other.x && self.y != other.y {
+            return None;
+        }
+
+        Some(if self.x < other.x {
             Direction::Right
         } else if self.x > other.x {
-            assert_eq!(self.y, other.y);
             Direction::Left
+        } else if self.y < other.y {
+            Direction::Down
+        } else if self.y > other.y {
+            Direction::Up
         } else {
-            if self.y < other.y {
-                assert_eq!(self.x, other.x);
-                Direction::Down
-            } else if self.y > other.y {
-                assert_eq!(self.x, other.x);
-                Direction::Up
-            } else {
-                Direction::None
-            }
-        }
+            Direction::None
+        })
     }
 }
 
-impl<'a> From<&'a str> for Pos2d {
-    fn from(coords: &str) -> Self {
-        let mut parts = coords.split(",");
-        let x = parts.next().unwrap().trim().parse().unwrap();
-        let y = parts.next().unwrap().trim().parse().unwrap();
+/// Parses `x,y`, as used by day14's rock paths.
+impl FromStr for Pos2d {
+    type Err = ParseError;
 
-        Pos2d { x, y }
+    fn from_str(coords: &str) -> Result<Self, ParseError> {
+        let Some((x, y)) = coords.split_once(',') else {
+            return Err(ParseError::at(coords, coords.trim(), "expected coordinates like 498,4"));
+        };
+        let x = error::number(coords, x.trim())?;
+        let y = error::number(coords, y.trim())?;
+
+        Ok(Pos2d { x, y })
     }
 }
 
-impl Default for Pos2d {
-    fn default() -> Self {
-        Self {
-            x: Default::default(),
-            y: Default::default(),
-        }
-    }
+#[test]
+fn test() {
+    let pos: Pos2d = "498,4".parse().unwrap();
+    assert_eq!(pos, Pos2d::new(498, 4));
+    assert_eq!(pos.cmp_no_diagonal(&Pos2d::new(498, 6)), Some(Direction::Down));
+    assert_eq!(pos.cmp_no_diagonal(&pos), Some(Direction::None));
+    assert_eq!(pos.cmp_no_diagonal(&Pos2d::new(496, 6)), None);
+    assert_eq!(pos.move_in_direction(Direction::Left), Pos2d::new(497, 4));
+
+    let err = "498;4".parse::<Pos2d>().unwrap_err();
+    assert_eq!(err.to_string(), "line 1, column 1: expected coordinates like 498,4, found `498;4`");
 }
@@ -0,0 +1,448 @@
+//! Reading a saved puzzle page.
+//!
+//! The description is in one `<article>` per part. The example input is
+//! the first `<pre><code>` block of part 1, and each part's example answer
+//! is the last emphasised code, `<code><em>24000</em></code>`, in its
+//! article. Part 2 is only there once part 1 is solved.
+
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Puzzle {
+    /// From the page title, when the page has one.
+    pub year: Option<u32>,
+    pub day: u32,
+    pub title: String,
+    pub markdown: String,
+    pub example: Option<String>,
+    /// The example's answer for each part on the page.
+    pub answers: BTreeMap<u32, String>,
+}
+
+impl std::str::FromStr for Puzzle {
+    type Err = String;
+
+    fn from_str(html: &str) -> Result<Puzzle, String> {
+        let tokens = tokenize(html);
+
+        let articles: Vec<&[Token]> = articles(&tokens);
+        if articles.is_empty() {
+            return Err("the page has no puzzle description, save it while logged in".to_owned());
+        }
+
+        let heading = heading(articles[0]).ok_or("the description has no `--- Day N: Title ---` heading")?;
+        let (day, title) = heading
+            .trim_matches(|c: char| c == '-' || c.is_whitespace())
+            .strip_prefix("Day ")
+            .and_then(|rest| rest.split_once(": "))
+            .and_then(|(day, title)| Some((day.parse().ok()?, title.to_owned())))
+            .ok_or(format!("unexpected heading `{heading}`"))?;
+
+        let year = element_text(&tokens, "title")
+            .and_then(|title| title.rsplit(' ').next().and_then(|year| year.parse().ok()));
+
+        let mut answers = BTreeMap::new();
+        for (part, article) in (1..).zip(&articles) {
+            if let Some(answer) = emphasised_code(article).pop() {
+                answers.insert(part, answer);
+            }
+        }
+
+        Ok(Puzzle {
+            year,
+            day,
+            title,
+            markdown: articles.iter().map(|article| markdown(article)).collect::<Vec<_>>().join("\n"),
+            example: pre_blocks(articles[0]).into_iter().next(),
+            answers,
+        })
+    }
+}
+
+impl Puzzle {
+    /// Writes the description to `dayNN.md` in `puzzles` and the example
+    /// and its answers to `dayNN-1.txt` and `dayNN-1.answers` in `examples`,
+    /// returning the paths written. Example files that already have
+    /// something in them, and differ, are only replaced with `force`.
+    pub fn write(&self, puzzles: &Path, examples: &Path, force: bool) -> io::Result<Vec<PathBuf>> {
+        let day = self.day;
+        let mut files = vec![(puzzles.join(format!("day{day:02}.md")), self.markdown.clone())];
+
+        if let Some(example) = &self.example {
+            let mut answers = String::from("# part\tanswer\n");
+            for (part, answer) in &self.answers {
+                answers.push_str(&format!("{part}\t{}\n", crate::answers::escape(answer)));
+            }
+            files.push((examples.join(format!("day{day:02}-1.txt")), format!("{example}\n")));
+            files.push((examples.join(format!("day{day:02}-1.answers")), answers));
+        }
+
+        if !force {
+            for (path, contents) in &files[1..] {
+                match fs::read_to_string(path) {
+                    Ok(existing) if existing != *contents && !is_placeholder(&existing) => {
+                        return Err(io::Error::new(
+                            io::ErrorKind::AlreadyExists,
+                            format!("{} already has other contents, use --force to replace it", path.display()),
+                        ))
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let mut written = vec![];
+        for (path, contents) in files {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(&path, contents)?;
+            written.push(path);
+        }
+        Ok(written)
+    }
+}
+
+/// What `aoc new` leaves in example files.
+fn is_placeholder(contents: &str) -> bool {
+    contents.lines().all(|line| line.trim().is_empty() || line.starts_with('#'))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token<'a> {
+    /// A tag name, lowercased, and its `href`, if any.
+    Open(String, Option<String>),
+    Close(String),
+    Text(&'a str),
+}
+
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+        if !rest.starts_with('<') {
+            let end = rest.find('<').unwrap_or(rest.len());
+            tokens.push(Token::Text(&rest[..end]));
+            rest = &rest[end..];
+            continue;
+        }
+
+        // A page cut off inside a tag ends with whatever text was there.
+        let Some(end) = rest.find('>') else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+        let tag = rest[1..end].trim_end_matches('/');
+        rest = &rest[end + 1..];
+
+        let (closing, tag) = match tag.strip_prefix('/') {
+            Some(tag) => (true, tag),
+            None => (false, tag),
+        };
+        let name = tag.split_whitespace().next().unwrap_or("").to_ascii_lowercase();
+
+        if closing {
+            tokens.push(Token::Close(name));
+        } else if name == "script" || name == "style" {
+            let close = format!("</{name}");
+            rest = rest.find(&close).map_or("", |end| &rest[end..]);
+        } else {
+            tokens.push(Token::Open(name, attribute(tag, "href").map(decode)));
+        }
+    }
+
+    tokens
+}
+
+/// The value of `name="..."` in the inside of a tag.
+fn attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let start = tag.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = start + tag[start..].find('"')?;
+    Some(&tag[start..end])
+}
+
+/// Replaces character references with the characters they stand for.
+fn decode(text: &str) -> String {
+    let mut decoded = String::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let Some(end) = rest.find(';').filter(|&end| end <= 10) else {
+            decoded.push('&');
+            rest = &rest[1..];
+            continue;
+        };
+        let c = match &rest[1..end] {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some('\u{a0}'),
+            entity => entity
+                .strip_prefix("#x")
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| entity.strip_prefix('#').map(str::parse))
+                .and_then(Result::ok)
+                .and_then(char::from_u32),
+        };
+        match c {
+            Some(c) => {
+                decoded.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+/// The contents of each `<article>`.
+fn articles<'t, 'a>(tokens: &'t [Token<'a>]) -> Vec<&'t [Token<'a>]> {
+    let mut articles = vec![];
+    let mut start = None;
+
+    for (i, token) in tokens.iter().enumerate() {
+        match token {
+            Token::Open(name, _) if name == "article" => start = Some(i + 1),
+            Token::Close(name) if name == "article" => {
+                if let Some(start) = start.take() {
+                    articles.push(&tokens[start..i]);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    articles
+}
+
+/// The decoded text of the first `name` element.
+fn element_text(tokens: &[Token], name: &str) -> Option<String> {
+    let start = tokens.iter().position(|token| matches!(token, Token::Open(n, _) if n == name))?;
+    let mut text = String::new();
+
+    for token in &tokens[start + 1..] {
+        match token {
+            Token::Close(n) if n == name => break,
+            Token::Text(t) => text.push_str(&decode(t)),
+            _ => {}
+        }
+    }
+
+    Some(text.trim().to_owned())
+}
+
+fn heading(article: &[Token]) -> Option<String> {
+    element_text(article, "h2")
+}
+
+/// The text of every `<pre>` block, without its final newline.
+fn pre_blocks(article: &[Token]) -> Vec<String> {
+    let mut blocks = vec![];
+    let mut current: Option<String> = None;
+
+    for token in article {
+        match token {
+            Token::Open(name, _) if name == "pre" => current = Some(String::new()),
+            Token::Close(name) if name == "pre" => blocks.extend(current.take()),
+            Token::Text(text) => {
+                if let Some(block) = &mut current {
+                    block.push_str(&decode(text));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    blocks.into_iter().map(|block| block.trim_end_matches('\n').to_owned()).collect()
+}
+
+/// The text of every `<code><em>` or `<em><code>` outside `<pre>`.
+fn emphasised_code(article: &[Token]) -> Vec<String> {
+    let (mut code, mut em, mut pre) = (0, 0, 0);
+    let mut found = vec![];
+    let mut current: Option<String> = None;
+
+    for token in article {
+        match token {
+            Token::Open(name, _) => match name.as_str() {
+                "code" => code += 1,
+                "em" => em += 1,
+                "pre" => pre += 1,
+                _ => {}
+            },
+            Token::Close(name) => {
+                match name.as_str() {
+                    "code" => code -= 1,
+                    "em" => em -= 1,
+                    "pre" => pre -= 1,
+                    _ => {}
+                }
+                if code == 0 || em == 0 {
+                    found.extend(current.take());
+                }
+            }
+            Token::Text(text) if code > 0 && em > 0 && pre == 0 => {
+                current.get_or_insert_with(String::new).push_str(&decode(text));
+            }
+            Token::Text(_) => {}
+        }
+    }
+
+    found.into_iter().map(|answer| answer.trim().to_owned()).filter(|answer| !answer.is_empty()).collect()
+}
+
+/// The article as Markdown: headings, paragraphs, lists, links, inline code
+/// and emphasis, and fenced `<pre>` blocks.
+fn markdown(article: &[Token]) -> String {
+    let mut out = String::new();
+    let (mut pre, mut code) = (false, false);
+    let mut links = vec![];
+
+    for token in article {
+        match token {
+            Token::Open(name, href) => match name.as_str() {
+                "h2" => out.push_str("\n## "),
+                "p" => out.push('\n'),
+                "pre" => {
+                    pre = true;
+                    out.push_str("\n```\n");
+                }
+                "code" if !pre => {
+                    code = true;
+                    out.push('`');
+                }
+                "em" if !pre && !code => out.push('*'),
+                "li" => out.push_str("\n- "),
+                "a" => {
+                    links.push(href.clone());
+                    out.push('[');
+                }
+                _ => {}
+            },
+            Token::Close(name) => match name.as_str() {
+                "h2" | "p" | "ul" => out.push_str("\n\n"),
+                "pre" => {
+                    pre = false;
+                    if !out.ends_with('\n') {
+                        out.push('\n');
+                    }
+                    out.push_str("```\n\n");
+                }
+                "code" if !pre => {
+                    code = false;
+                    out.push('`');
+                }
+                "em" if !pre && !code => out.push('*'),
+                "a" => match links.pop().flatten() {
+                    Some(href) => out.push_str(&format!("]({href})")),
+                    None => out.push(']'),
+                },
+                _ => {}
+            },
+            Token::Text(text) if pre => out.push_str(&decode(text)),
+            Token::Text(text) => {
+                let text = decode(text);
+                let collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+                let at_line_start = out.is_empty() || out.ends_with('\n') || out.ends_with("- ");
+                if text.starts_with(char::is_whitespace) && !at_line_start && !out.ends_with(' ') {
+                    out.push(' ');
+                }
+                out.push_str(&collapsed);
+                if text.ends_with(char::is_whitespace) && !collapsed.is_empty() {
+                    out.push(' ');
+                }
+            }
+        }
+    }
+
+    // Tidies the spacing left by nested blocks.
+    let mut tidy = String::new();
+    for line in out.lines().map(str::trim_end) {
+        if !(line.is_empty() && (tidy.is_empty() || tidy.ends_with("\n\n"))) {
+            tidy.push_str(line);
+            tidy.push('\n');
+        }
+    }
+    tidy.trim_end().to_owned() + "\n"
+}
+
+#[test]
+fn test() {
+    let html = r#"<!DOCTYPE html>
+<html lang="en-us">
+<head><title>Day 1 - Advent of Code 2022</title><style>p { color: red; }</style></head>
+<body><main>
+<script>window.x = "<article>";</script>
+<article class="day-desc"><h2>--- Day 1: Calorie Counting ---</h2><p>Santa's <a href="/2022/about">reindeer</a> eat &lt;food&gt;.</p>
+<p>For example:</p>
+<pre><code>1000
+2000
+
+<em>3000</em>
+</code></pre>
+<ul>
+<li>The first Elf carries <code>3000</code> Calories.</li>
+</ul>
+<p>That Elf carries <code><em>24000</em></code> Calories, the <em>most</em>.</p>
+</article>
+<p>Your puzzle answer was <code>70116</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>The top three carry <em><code>45000</code></em> Calories.</p></article>
+</main></body></html>"#;
+
+    let puzzle: Puzzle = html.parse().unwrap();
+    assert_eq!((puzzle.year, puzzle.day, puzzle.title.as_str()), (Some(2022), 1, "Calorie Counting"));
+    assert_eq!(puzzle.example.as_deref(), Some("1000\n2000\n\n3000"));
+    assert_eq!(puzzle.answers, BTreeMap::from([(1, "24000".to_owned()), (2, "45000".to_owned())]));
+    assert_eq!(
+        puzzle.markdown,
+        "## --- Day 1: Calorie Counting ---\n\n\
+         Santa's [reindeer](/2022/about) eat <food>.\n\n\
+         For example:\n\n\
+         ```\n1000\n2000\n\n3000\n```\n\n\
+         - The first Elf carries `3000` Calories.\n\n\
+         That Elf carries `24000` Calories, the *most*.\n\n\
+         ## --- Part Two ---\n\n\
+         The top three carry *`45000`* Calories.\n"
+    );
+
+    assert_eq!(decode("&#x27;a&#39; &amp;lt; &bogus; & b"), "'a' &lt; &bogus; & b");
+    assert!("<html><body>log in</body></html>".parse::<Puzzle>().is_err());
+    assert_eq!(tokenize("abc<"), [Token::Text("abc"), Token::Text("<")]);
+    assert_eq!(tokenize("<p>x<é"), [Token::Open("p".to_owned(), None), Token::Text("x"), Token::Text("<é")]);
+    assert!(format!("{html}<").parse::<Puzzle>().is_ok());
+
+    let root = std::env::temp_dir().join(format!("aoc-puzzle-{}", std::process::id()));
+    let (puzzles, examples) = (root.join("puzzles"), root.join("examples"));
+    fs::create_dir_all(&examples).unwrap();
+    fs::write(examples.join("day01-1.answers"), "# part\tanswer\n").unwrap();
+
+    assert_eq!(puzzle.write(&puzzles, &examples, false).unwrap().len(), 3);
+    assert_eq!(fs::read_to_string(examples.join("day01-1.answers")).unwrap(), "# part\tanswer\n1\t24000\n2\t45000\n");
+    assert_eq!(fs::read_to_string(examples.join("day01-1.txt")).unwrap(), "1000\n2000\n\n3000\n");
+    assert!(puzzle.write(&puzzles, &examples, false).is_ok());
+
+    fs::write(examples.join("day01-1.txt"), "1\n").unwrap();
+    assert!(puzzle.write(&puzzles, &examples, false).is_err());
+    assert!(puzzle.write(&puzzles, &examples, true).is_ok());
+
+    fs::remove_dir_all(root).unwrap();
+}
@@ -0,0 +1,296 @@
+use std::{
+    fmt::Write,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    answers::{Answers, Status},
+    memory::{self, Usage},
+    solution::Parsed,
+    Day,
+};
+
+/// One part of one day's run, as written to JSON and CSV reports.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    /// `Err` holds the message of whatever stopped the part being solved,
+    /// including a missing input or a parse error.
+    pub answer: Result<String, String>,
+    /// Shared by both parts of a day, since the input is parsed once.
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+    pub status: Status,
+    /// The answer came from the answer cache, so nothing was timed.
+    pub cached: bool,
+    /// Heap use, when the runner counts allocations; see `memory`.
+    pub parse_memory: Option<Usage>,
+    pub solve_memory: Option<Usage>,
+}
+
+impl Record {
+    /// A record for a part that could not be run at all.
+    pub fn failed(year: u32, day: u32, part: u32, parse_time: Duration, error: impl ToString) -> Record {
+        Record {
+            year,
+            day,
+            part,
+            answer: Err(error.to_string()),
+            parse_time,
+            solve_time: Duration::ZERO,
+            status: Status::Unknown,
+            cached: false,
+            parse_memory: None,
+            solve_memory: None,
+        }
+    }
+
+    /// A record for an answer taken from the cache.
+    pub fn cached(year: u32, day: u32, part: u32, answer: String, status: Status) -> Record {
+        Record {
+            year,
+            day,
+            part,
+            answer: Ok(answer),
+            parse_time: Duration::ZERO,
+            solve_time: Duration::ZERO,
+            status,
+            cached: true,
+            parse_memory: None,
+            solve_memory: None,
+        }
+    }
+
+    /// `pass`, `fail`, `unknown`, or `error` when there is no answer.
+    pub fn status_name(&self) -> &'static str {
+        match (&self.answer, &self.status) {
+            (Err(_), _) => "error",
+            (_, Status::Pass) => "pass",
+            (_, Status::Fail(_)) => "fail",
+            (_, Status::Error) => "error",
+            (_, Status::Unknown) => "unknown",
+        }
+    }
+
+    fn expected(&self) -> Option<&str> {
+        match &self.status {
+            Status::Fail(expected) => Some(expected),
+            _ => None,
+        }
+    }
+}
+
+/// A day's input parsed once, for each of its parts to be solved from.
+pub struct Parse {
+    pub parsed: crate::Result<Parsed>,
+    pub time: Duration,
+    pub memory: Option<Usage>,
+}
+
+/// Parses `input` for `day`, timing it.
+pub fn parse(day: &Day, input: &str) -> Parse {
+    let start = Instant::now();
+    let (parsed, memory) = memory::measure(|| day.parse(input));
+    Parse { parsed, time: start.elapsed(), memory }
+}
+
+/// Solves `part` of `day` from `parse`, timing it and checking the answer
+/// against `answers`. `None` if the day has no such part.
+pub fn run_part(day: &Day, input: &str, parse: &Parse, part: u32, answers: &Answers) -> Option<Record> {
+    let parsed = match &parse.parsed {
+        Ok(parsed) => parsed,
+        Err(err) => return Some(Record::failed(day.year, day.day, part, parse.time, err)),
+    };
+
+    let start = Instant::now();
+    let (answer, solve_memory) = memory::measure(|| day.part(part, parsed));
+    let answer = answer?;
+    let solve_time = start.elapsed();
+
+    let status = match &answer {
+        Ok(answer) => answers.check(day.year, day.day, part, input, answer),
+        Err(_) => Status::Unknown,
+    };
+
+    Some(Record {
+        year: day.year,
+        day: day.day,
+        part,
+        answer: answer.map(|answer| answer.to_string()).map_err(|err| err.to_string()),
+        parse_time: parse.time,
+        solve_time,
+        status,
+        cached: false,
+        parse_memory: parse.memory,
+        solve_memory,
+    })
+}
+
+/// Parses `input` and solves `parts` of `day`, timing each step and checking
+/// the answers against `answers`.
+pub fn run(day: &Day, input: &str, parts: &[u32], answers: &Answers) -> Vec<Record> {
+    let parsed = parse(day, input);
+    parts.iter().filter_map(|&part| run_part(day, input, &parsed, part, answers)).collect()
+}
+
+/// A JSON array with one object per record. Times are in nanoseconds;
+/// `answer`, `expected`, `error` and the memory objects are null when they
+/// do not apply.
+pub fn json(records: &[Record]) -> String {
+    let mut json = String::from("[");
+
+    for (i, record) in records.iter().enumerate() {
+        let (answer, error) = match &record.answer {
+            Ok(answer) => (Some(answer.as_str()), None),
+            Err(err) => (None, Some(err.as_str())),
+        };
+
+        write!(
+            json,
+            "{}\n  {{\"year\": {}, \"day\": {}, \"part\": {}, \"answer\": {}, \"parse_ns\": {}, \"solve_ns\": {}, \"status\": \"{}\", \"expected\": {}, \"error\": {}, \"cached\": {}, \"parse_memory\": {}, \"solve_memory\": {}}}",
+            if i == 0 { "" } else { "," },
+            record.year,
+            record.day,
+            record.part,
+            json_string(answer),
+            record.parse_time.as_nanos(),
+            record.solve_time.as_nanos(),
+            record.status_name(),
+            json_string(record.expected()),
+            json_string(error),
+            record.cached,
+            json_usage(record.parse_memory),
+            json_usage(record.solve_memory),
+        )
+        .unwrap();
+    }
+
+    json.push_str(if records.is_empty() { "]\n" } else { "\n]\n" });
+    json
+}
+
+/// CSV with a header row, quoted where needed so multi-line answers survive.
+pub fn csv(records: &[Record]) -> String {
+    let mut csv = String::from(
+        "year,day,part,answer,parse_ns,solve_ns,status,expected,error,cached,\
+         parse_allocations,parse_bytes,parse_peak,solve_allocations,solve_bytes,solve_peak\n",
+    );
+
+    for record in records {
+        let (answer, error) = match &record.answer {
+            Ok(answer) => (answer.as_str(), ""),
+            Err(err) => ("", err.as_str()),
+        };
+
+        writeln!(
+            csv,
+            "{},{},{},{},{},{},{},{},{},{},{},{}",
+            record.year,
+            record.day,
+            record.part,
+            csv_field(answer),
+            record.parse_time.as_nanos(),
+            record.solve_time.as_nanos(),
+            record.status_name(),
+            csv_field(record.expected().unwrap_or("")),
+            csv_field(error),
+            record.cached,
+            csv_usage(record.parse_memory),
+            csv_usage(record.solve_memory),
+        )
+        .unwrap();
+    }
+
+    csv
+}
+
+fn json_string(text: Option<&str>) -> String {
+    let Some(text) = text else {
+        return "null".to_owned();
+    };
+
+    let mut json = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(json, "\\u{:04x}", c as u32).unwrap(),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+fn json_usage(usage: Option<Usage>) -> String {
+    match usage {
+        Some(Usage { allocations, bytes, peak }) => {
+            format!("{{\"allocations\": {allocations}, \"bytes\": {bytes}, \"peak\": {peak}}}")
+        }
+        None => "null".to_owned(),
+    }
+}
+
+fn csv_usage(usage: Option<Usage>) -> String {
+    match usage {
+        Some(Usage { allocations, bytes, peak }) => format!("{allocations},{bytes},{peak}"),
+        None => ",,".to_owned(),
+    }
+}
+
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_owned()
+    }
+}
+
+#[test]
+fn test() {
+    let day02 = crate::day(2022, 2).unwrap();
+    let input = "A Y\nB X\nC Z";
+
+    let mut answers = Answers::default();
+    answers.insert(2022, 2, 1, input, &crate::Answer::Int(15));
+    answers.insert(2022, 2, 2, input, &crate::Answer::Int(13));
+
+    let records = run(day02, input, &[1, 2], &answers);
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].answer, Ok("15".to_owned()));
+    assert_eq!(records[0].status_name(), "pass");
+    assert_eq!(records[1].status, Status::Fail("13".to_owned()));
+
+    let failed = run(day02, "A Q", &[2], &answers);
+    assert_eq!(failed[0].status_name(), "error");
+    assert!(failed[0].answer.as_ref().unwrap_err().starts_with("parse error"));
+
+    let record = Record {
+        year: 2022,
+        day: 10,
+        part: 2,
+        answer: Ok("#,\n\"#".to_owned()),
+        parse_time: Duration::from_nanos(1500),
+        solve_time: Duration::from_nanos(20),
+        status: Status::Unknown,
+        cached: false,
+        parse_memory: Some(Usage { allocations: 2, bytes: 64, peak: 48 }),
+        solve_memory: None,
+    };
+    assert_eq!(
+        json(&[record.clone(), Record::failed(2022, 1, 1, Duration::ZERO, "no input")]),
+        "[\n  {\"year\": 2022, \"day\": 10, \"part\": 2, \"answer\": \"#,\\n\\\"#\", \"parse_ns\": 1500, \"solve_ns\": 20, \"status\": \"unknown\", \"expected\": null, \"error\": null, \"cached\": false, \"parse_memory\": {\"allocations\": 2, \"bytes\": 64, \"peak\": 48}, \"solve_memory\": null},\n  {\"year\": 2022, \"day\": 1, \"part\": 1, \"answer\": null, \"parse_ns\": 0, \"solve_ns\": 0, \"status\": \"error\", \"expected\": null, \"error\": \"no input\", \"cached\": false, \"parse_memory\": null, \"solve_memory\": null}\n]\n"
+    );
+    assert_eq!(json(&[]), "[]\n");
+    assert_eq!(
+        csv(&[record]),
+        "year,day,part,answer,parse_ns,solve_ns,status,expected,error,cached,\
+         parse_allocations,parse_bytes,parse_peak,solve_allocations,solve_bytes,solve_peak\n\
+         2022,10,2,\"#,\n\"\"#\",1500,20,unknown,,,false,2,64,48,,,\n"
+    );
+}
@@ -0,0 +1,194 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+const TEMPLATE: &str = r#"use crate::{
+    error::{Error, Result},
+    Solution,
+};
+
+pub struct DayNN;
+
+impl Solution for DayNN {
+    type Parsed = Vec<String>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<String>> {
+        Ok(input.lines().map(str::to_owned).collect())
+    }
+
+    fn part1(_lines: &Vec<String>) -> Result<u64> {
+        Err(Error::NoAnswer("not solved yet".to_owned()))
+    }
+
+    fn part2(_lines: &Vec<String>) -> Result<u64> {
+        Err(Error::NoAnswer("not solved yet".to_owned()))
+    }
+}
+
+#[test]
+fn test() {
+    let input = include_str!("../../input/YYYY/examples/dayNN-1.txt");
+    let lines = DayNN::parse(input).unwrap();
+
+    assert!(DayNN::part1(&lines).is_err());
+}
+"#;
+
+/// The source of a new day module that builds, registers and passes its test
+/// before anything is solved.
+pub fn module(year: u32, day: u32) -> String {
+    TEMPLATE.replace("YYYY", &year.to_string()).replace("NN", &format!("{day:02}"))
+}
+
+/// Adds `pub mod yYYYY;`, unless the year already has days, and a `DAYS`
+/// entry to the source of lib.rs, keeping both lists in order.
+pub fn register(lib: &str, year: u32, day: u32, title: &str) -> Result<String, String> {
+    let module = format!("pub mod y{year};");
+    let entry = format!("    Day::new::<y{year}::day{day:02}::Day{day:02}>({year}, {day}, {title:?}),");
+
+    if lib.lines().any(|line| line.starts_with(&format!("    Day::new::<y{year}::day{day:02}::"))) {
+        return Err(format!("{year} day {day} is already registered"));
+    }
+
+    let mut lines: Vec<&str> = lib.lines().collect();
+
+    if !lines.contains(&module.as_str()) {
+        let at = sorted_position(&lines, "pub mod y", &module).ok_or("lib.rs has no `pub mod yYYYY;` lines")?;
+        lines.insert(at, &module);
+    }
+
+    let table = lines
+        .iter()
+        .position(|line| line.starts_with("pub static DAYS"))
+        .ok_or("lib.rs has no `DAYS` table")?
+        + 1;
+    let at = table + lines[table..]
+        .iter()
+        .take_while(|line| line.starts_with("    Day::new::<y"))
+        .filter(|line| **line < entry.as_str())
+        .count();
+    lines.insert(at, &entry);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds `pub mod dayNN;` to the source of a year's mod.rs, or starts one
+/// when `year_mod` is `None`.
+pub fn register_module(year_mod: Option<&str>, year: u32, day: u32) -> Result<String, String> {
+    let module = format!("pub mod day{day:02};");
+
+    let Some(year_mod) = year_mod else {
+        return Ok(format!("//! Advent of Code {year}.\n\n{module}\n"));
+    };
+    if year_mod.lines().any(|line| line == module) {
+        return Err(format!("{year} day {day} is already a module"));
+    }
+
+    let mut lines: Vec<&str> = year_mod.lines().collect();
+    let at = sorted_position(&lines, "pub mod day", &module).ok_or(format!("src/y{year}/mod.rs has no `pub mod dayNN;` lines"))?;
+    lines.insert(at, &module);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Where `line` goes in the first run of lines starting with `prefix`.
+fn sorted_position(lines: &[&str], prefix: &str, line: &str) -> Option<usize> {
+    let first = lines.iter().position(|l| l.starts_with(prefix))?;
+
+    Some(first + lines[first..].iter().take_while(|l| l.starts_with(prefix)).filter(|l| **l < line).count())
+}
+
+/// Creates everything a new day needs under the crate root `root`, including
+/// an example with an empty answers file, and returns the paths written.
+/// Fails without touching anything if the day exists.
+pub fn create(root: &Path, year: u32, day: u32, title: &str) -> io::Result<Vec<PathBuf>> {
+    let invalid = |message: String| io::Error::new(io::ErrorKind::InvalidInput, message);
+
+    if !(1..=25).contains(&day) {
+        return Err(invalid(format!("day {day} is not between 1 and 25")));
+    }
+    if year < 2015 {
+        return Err(invalid(format!("there was no Advent of Code in {year}")));
+    }
+
+    let source = root.join(format!("src/y{year}/day{day:02}.rs"));
+    if source.exists() {
+        return Err(invalid(format!("{} already exists", source.display())));
+    }
+
+    let lib_path = root.join("src/lib.rs");
+    let lib = register(&fs::read_to_string(&lib_path)?, year, day, title).map_err(invalid)?;
+
+    let mod_path = root.join(format!("src/y{year}/mod.rs"));
+    let year_mod = match fs::read_to_string(&mod_path) {
+        Ok(year_mod) => Some(year_mod),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => None,
+        Err(err) => return Err(err),
+    };
+    let year_mod = register_module(year_mod.as_deref(), year, day).map_err(invalid)?;
+
+    let examples = root.join(format!("input/{year}/examples"));
+    let example = examples.join(format!("day{day:02}-1.txt"));
+    let expected = examples.join(format!("day{day:02}-1.answers"));
+    let input = root.join(format!("input/{year}/day{day:02}.txt"));
+
+    fs::create_dir_all(root.join(format!("src/y{year}")))?;
+    fs::create_dir_all(&examples)?;
+    fs::write(&source, module(year, day))?;
+    fs::write(&mod_path, year_mod)?;
+    fs::write(&lib_path, lib)?;
+
+    let mut written = vec![source, mod_path, lib_path];
+    for (placeholder, contents) in [(example, ""), (expected, "# part\tanswer\n"), (input, "")] {
+        if !placeholder.exists() {
+            fs::write(&placeholder, contents)?;
+            written.push(placeholder);
+        }
+    }
+
+    Ok(written)
+}
+
+#[test]
+fn test() {
+    let lib = "pub mod y2022;\n\npub mod error;\n\npub static DAYS: &[Day] = &[\n    Day::new::<y2022::day01::Day01>(2022, 1, \"One\"),\n    Day::new::<y2022::day03::Day03>(2022, 3, \"Three\"),\n];\n";
+
+    let lib = register(lib, 2022, 2, "Two").unwrap();
+    assert_eq!(
+        lib,
+        "pub mod y2022;\n\npub mod error;\n\npub static DAYS: &[Day] = &[\n    Day::new::<y2022::day01::Day01>(2022, 1, \"One\"),\n    Day::new::<y2022::day02::Day02>(2022, 2, \"Two\"),\n    Day::new::<y2022::day03::Day03>(2022, 3, \"Three\"),\n];\n"
+    );
+    assert!(register(&lib, 2022, 2, "Two").is_err());
+
+    let lib = register(&lib, 2021, 5, "Five").unwrap();
+    assert!(lib.starts_with("pub mod y2021;\npub mod y2022;\n"));
+    assert!(lib.contains("[\n    Day::new::<y2021::day05::Day05>(2021, 5, \"Five\"),\n    Day::new::<y2022::day01"));
+
+    assert_eq!(register_module(None, 2023, 4).unwrap(), "//! Advent of Code 2023.\n\npub mod day04;\n");
+    assert_eq!(
+        register_module(Some("//! Advent of Code 2023.\n\npub mod day04;\n"), 2023, 1).unwrap(),
+        "//! Advent of Code 2023.\n\npub mod day01;\npub mod day04;\n"
+    );
+
+    assert!(module(2022, 7).contains("pub struct Day07;"));
+    assert!(module(2022, 7).contains("../../input/2022/examples/day07-1.txt"));
+
+    let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+    fs::create_dir_all(root.join("src")).unwrap();
+    fs::write(root.join("src/lib.rs"), "pub mod y2022;\n\npub static DAYS: &[Day] = &[\n    Day::new::<y2022::day01::Day01>(2022, 1, \"One\"),\n];\n").unwrap();
+
+    assert_eq!(create(&root, 2022, 15, "Beacon Exclusion Zone").unwrap().len(), 6);
+    assert!(fs::read_to_string(root.join("src/lib.rs")).unwrap().contains("(2022, 15, \"Beacon Exclusion Zone\")"));
+    assert_eq!(fs::read_to_string(root.join("src/y2022/mod.rs")).unwrap(), "//! Advent of Code 2022.\n\npub mod day15;\n");
+    assert!(root.join("input/2022/examples/day15-1.txt").exists());
+    assert!(create(&root, 2022, 15, "Again").is_err());
+    assert!(create(&root, 2022, 26, "Too late").is_err());
+
+    assert!(create(&root, 2023, 1, "Trebuchet?!").is_ok());
+    assert!(fs::read_to_string(root.join("src/lib.rs")).unwrap().starts_with("pub mod y2022;\npub mod y2023;\n"));
+
+    fs::remove_dir_all(root).unwrap();
+}
@@ -0,0 +1,150 @@
+use std::any::Any;
+
+use crate::{
+    cancel::{self, Token},
+    error::Result,
+    Answer,
+};
+
+/// A single day's puzzle: parse the input once, then answer both parts from
+/// the parsed form.
+pub trait Solution {
+    type Parsed: Send + Sync + 'static;
+    type Part1: Into<Answer> + 'static;
+    type Part2: Into<Answer> + 'static;
+
+    /// Bump whenever a change could alter the answers, so answers cached by
+    /// an older solver are not reused.
+    const VERSION: u32 = 1;
+
+    /// Other implementations of each part, by name. They must give the same
+    /// answers as `part1` and `part2`, which `crosscheck` verifies.
+    const PART1_VARIANTS: &'static [Variant<Self::Parsed, Self::Part1>] = &[];
+    const PART2_VARIANTS: &'static [Variant<Self::Parsed, Self::Part2>] = &[];
+
+    fn parse(input: &str) -> Result<Self::Parsed>;
+    fn part1(parsed: &Self::Parsed) -> Result<Self::Part1>;
+    fn part2(parsed: &Self::Parsed) -> Result<Self::Part2>;
+}
+
+/// A named alternative implementation of a part.
+pub type Variant<P, A> = (&'static str, fn(&P) -> Result<A>);
+
+/// The name `Day` lists the trait's own `part1` and `part2` under.
+pub const DEFAULT_VARIANT: &str = "default";
+
+/// The parsed input of a day, with its concrete type erased.
+pub type Parsed = Box<dyn Any + Send + Sync>;
+
+/// A registry entry. Wraps a `Solution` behind plain function pointers so days
+/// with different input and answer types can live in one table.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub year: u32,
+    pub day: u32,
+    pub title: &'static str,
+    pub version: u32,
+    parse: fn(&str) -> Result<Parsed>,
+    part1: fn(&Parsed) -> Result<Answer>,
+    part2: fn(&Parsed) -> Result<Answer>,
+    variants: fn(u32) -> Vec<&'static str>,
+    variant: fn(u32, &str, &Parsed) -> Option<Result<Answer>>,
+}
+
+impl Day {
+    pub const fn new<S: Solution>(year: u32, day: u32, title: &'static str) -> Day {
+        Day {
+            year,
+            day,
+            title,
+            version: S::VERSION,
+            parse: parse::<S>,
+            part1: part1::<S>,
+            part2: part2::<S>,
+            variants: variants::<S>,
+            variant: variant::<S>,
+        }
+    }
+
+    pub fn parse(&self, input: &str) -> Result<Parsed> {
+        (self.parse)(input)
+    }
+
+    /// Runs within the current `cancel::budget()`. Panics if `parsed` did
+    /// not come from this day's `parse`.
+    pub fn part1(&self, parsed: &Parsed) -> Result<Answer> {
+        budgeted(|| (self.part1)(parsed))
+    }
+
+    /// Runs within the current `cancel::budget()`. Panics if `parsed` did
+    /// not come from this day's `parse`.
+    pub fn part2(&self, parsed: &Parsed) -> Result<Answer> {
+        budgeted(|| (self.part2)(parsed))
+    }
+
+    pub fn part(&self, part: u32, parsed: &Parsed) -> Option<Result<Answer>> {
+        match part {
+            1 => Some(self.part1(parsed)),
+            2 => Some(self.part2(parsed)),
+            _ => None,
+        }
+    }
+
+    /// Names of every implementation of `part`, `DEFAULT_VARIANT` first;
+    /// empty for a part that does not exist.
+    pub fn variants(&self, part: u32) -> Vec<&'static str> {
+        match part {
+            1 | 2 => [DEFAULT_VARIANT].into_iter().chain((self.variants)(part)).collect(),
+            _ => vec![],
+        }
+    }
+
+    /// Solves `part` with the implementation called `name`, within the
+    /// current budget like `part`. `None` if there is no such implementation.
+    pub fn variant(&self, part: u32, name: &str, parsed: &Parsed) -> Option<Result<Answer>> {
+        match name {
+            DEFAULT_VARIANT => self.part(part, parsed),
+            name => budgeted(|| (self.variant)(part, name, parsed)),
+        }
+    }
+}
+
+fn budgeted<T>(f: impl FnOnce() -> T) -> T {
+    cancel::within(&Token::new(cancel::budget()), f)
+}
+
+fn parse<S: Solution>(input: &str) -> Result<Parsed> {
+    Ok(Box::new(S::parse(input)?))
+}
+
+fn part1<S: Solution>(parsed: &Parsed) -> Result<Answer> {
+    S::part1(downcast::<S>(parsed)).map(Into::into)
+}
+
+fn part2<S: Solution>(parsed: &Parsed) -> Result<Answer> {
+    S::part2(downcast::<S>(parsed)).map(Into::into)
+}
+
+fn variants<S: Solution>(part: u32) -> Vec<&'static str> {
+    match part {
+        1 => S::PART1_VARIANTS.iter().map(|(name, _)| *name).collect(),
+        2 => S::PART2_VARIANTS.iter().map(|(name, _)| *name).collect(),
+        _ => vec![],
+    }
+}
+
+fn variant<S: Solution>(part: u32, name: &str, parsed: &Parsed) -> Option<Result<Answer>> {
+    let parsed = downcast::<S>(parsed);
+
+    match part {
+        1 => S::PART1_VARIANTS.iter().find(|(n, _)| *n == name).map(|(_, f)| f(parsed).map(Into::into)),
+        2 => S::PART2_VARIANTS.iter().find(|(n, _)| *n == name).map(|(_, f)| f(parsed).map(Into::into)),
+        _ => None,
+    }
+}
+
+fn downcast<S: Solution>(parsed: &Parsed) -> &S::Parsed {
+    parsed
+        .downcast_ref()
+        .expect("parsed input belongs to a different day")
+}
@@ -0,0 +1,130 @@
+//! Solving straight from a reader, for inputs too big to hold in memory.
+//!
+//! A [`Streaming`] day reads its input once, solving both parts as it goes,
+//! and holds on to no more than a line or so of it. Answers are the same as
+//! parsing the whole input with [`Solution`], and so are parse errors, line
+//! and column included.
+
+use std::{
+    io::{self, BufRead},
+    path::PathBuf,
+};
+
+use crate::{
+    answer::Answer,
+    error::{Error, Result},
+    input::InputError,
+    y2022::{day01::Day01, day02::Day02, day03::Day03, day04::Day04, day06::Day06, day09::Day09, day10::Day10},
+    Solution,
+};
+
+/// Both answers from one pass over the input. The outer error is for input
+/// that cannot be read or parsed, the inner ones for a part that has no
+/// answer.
+pub type Streamed<A, B> = Result<(Result<A>, Result<B>)>;
+
+pub trait Streaming: Solution {
+    /// Reads all of `input` and answers both parts.
+    fn stream(input: impl BufRead) -> Streamed<Self::Part1, Self::Part2>;
+}
+
+/// A `Streaming::stream` with its answer types erased.
+pub type Stream = fn(&mut dyn BufRead) -> Result<[Result<Answer>; 2]>;
+
+/// The streaming solver for `day` of `year`, if it has one.
+pub fn solver(year: u32, day: u32) -> Option<Stream> {
+    match (year, day) {
+        (2022, 1) => Some(erased::<Day01>),
+        (2022, 2) => Some(erased::<Day02>),
+        (2022, 3) => Some(erased::<Day03>),
+        (2022, 4) => Some(erased::<Day04>),
+        (2022, 6) => Some(erased::<Day06>),
+        (2022, 9) => Some(erased::<Day09>),
+        (2022, 10) => Some(erased::<Day10>),
+        _ => None,
+    }
+}
+
+fn erased<S: Streaming>(input: &mut dyn BufRead) -> Result<[Result<Answer>; 2]> {
+    let (part1, part2) = S::stream(input)?;
+
+    Ok([part1.map(Into::into), part2.map(Into::into)])
+}
+
+/// Calls `f` with each line of `input` and its 1-based number, reusing one
+/// buffer. Line endings are dropped, and so are blank lines at the very end,
+/// as `input::normalise` does for whole inputs.
+pub fn lines(mut input: impl BufRead, mut f: impl FnMut(usize, &str) -> Result<()>) -> Result<()> {
+    let mut line = String::new();
+    let mut blank = 0;
+
+    for number in 1.. {
+        line.clear();
+        if input.read_line(&mut line).map_err(read_error)? == 0 {
+            break;
+        }
+
+        let text = line.strip_suffix('\n').unwrap_or(&line);
+        let text = text.strip_suffix('\r').unwrap_or(text);
+        if text.is_empty() {
+            blank += 1;
+            continue;
+        }
+
+        for blank_number in number - blank..number {
+            f(blank_number, "")?;
+        }
+        blank = 0;
+        f(number, text)?;
+    }
+
+    Ok(())
+}
+
+pub(crate) fn read_error(err: io::Error) -> Error {
+    InputError::Io(PathBuf::from("<stream>"), err).into()
+}
+
+#[test]
+fn test() {
+    let mut seen = vec![];
+    lines("a\r\n\nb\n\n\n".as_bytes(), |number, line| {
+        seen.push((number, line.to_owned()));
+        Ok(())
+    })
+    .unwrap();
+    assert_eq!(seen, [(1, "a".to_owned()), (2, String::new()), (3, "b".to_owned())]);
+
+    let err = lines("ok\n\u{0}bad\n".as_bytes(), |number, line| match line {
+        "ok" => Ok(()),
+        _ => Err(crate::ParseError::at(line, line, "expected ok").on_line(number).into()),
+    })
+    .unwrap_err();
+    assert_eq!(err.to_string(), "parse error: line 2, column 1: expected ok, found `\u{0}bad`");
+}
+
+/// Every streaming day gives the same answers as its `Solution` on every
+/// example.
+#[test]
+fn examples() {
+    for year in crate::years() {
+        for example in crate::examples::discover(&crate::examples::dir(year)).unwrap() {
+            let Some(stream) = solver(year, example.day) else {
+                continue;
+            };
+            let day = crate::day(year, example.day).unwrap();
+            let parsed = day.parse(&example.input).unwrap();
+
+            let streamed = stream(&mut example.input.as_bytes()).unwrap();
+            for (part, answer) in [1, 2].into_iter().zip(streamed) {
+                let expected = day.part(part, &parsed).unwrap();
+                assert_eq!(
+                    answer.map_err(|err| err.to_string()),
+                    expected.map_err(|err| err.to_string()),
+                    "{} part {part}",
+                    example.path.display()
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,393 @@
+//! Submitting answers to the Advent of Code site.
+//!
+//! Every answer sent and what the site made of it is kept in
+//! `submissions.txt` next to the inputs, so an answer already known to be
+//! wrong, or outside the bounds earlier too high and too low answers set,
+//! is refused locally instead of costing another wait.
+
+use std::{
+    fmt, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    answers::{escape, input_hash, unescape},
+    error::{number, ParseError},
+    fetch::{Client, FetchError},
+    http,
+    input::InputDir,
+};
+
+const HEADER: &str = "# year\tday\tpart\tinput hash\tsubmitted\tverdict\tanswer";
+
+/// What the site said about an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// Submitted too soon after the last answer, with the wait left if the
+    /// site said.
+    Wait(Option<Duration>),
+    /// The part was already solved, so the answer was not checked.
+    AlreadySolved,
+    /// A page none of the above matched, reduced to its text.
+    Unknown(String),
+}
+
+impl Verdict {
+    /// Whether the verdict says anything about the answer, and so belongs
+    /// in the history.
+    pub fn is_final(&self) -> bool {
+        matches!(self, Verdict::Right | Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow)
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Verdict::Right => "right",
+            Verdict::Wrong => "wrong",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            _ => "other",
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Right => write!(f, "right"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::TooHigh => write!(f, "wrong, too high"),
+            Verdict::TooLow => write!(f, "wrong, too low"),
+            Verdict::Wait(Some(left)) => write!(f, "answered too recently, wait {}s", left.as_secs()),
+            Verdict::Wait(None) => write!(f, "answered too recently, wait before trying again"),
+            Verdict::AlreadySolved => write!(f, "this part is already solved"),
+            Verdict::Unknown(text) => write!(f, "unrecognised response: {text}"),
+        }
+    }
+}
+
+/// Reads the verdict out of the page the site answers a submission with.
+pub fn parse_response(page: &str) -> Verdict {
+    let text = article_text(page);
+
+    if text.contains("That's the right answer") {
+        Verdict::Right
+    } else if text.contains("That's not the right answer") {
+        if text.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if text.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        }
+    } else if text.contains("You gave an answer too recently") {
+        Verdict::Wait(wait_left(&text))
+    } else if text.contains("You don't seem to be solving the right level") {
+        Verdict::AlreadySolved
+    } else {
+        Verdict::Unknown(text)
+    }
+}
+
+/// The text of the page's `<article>`, or of the whole page if it has none,
+/// with tags dropped and whitespace collapsed.
+fn article_text(page: &str) -> String {
+    let body = match (page.find("<article"), page.rfind("</article>")) {
+        (Some(start), Some(end)) if start < end => &page[start..end],
+        _ => page,
+    };
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in body.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parses `You have 1m 30s left to wait`.
+fn wait_left(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+
+    text[start..end].split(' ').try_fold(Duration::ZERO, |total, amount| {
+        let (count, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+        let seconds = match unit {
+            "h" => 3600,
+            "m" => 60,
+            "s" => 1,
+            _ => return None,
+        };
+        Some(total + Duration::from_secs(count.parse::<u64>().ok()? * seconds))
+    })
+}
+
+/// Posts `answer` to part `part` of `day`. Only answers the site has no
+/// say on yet should be sent; see `History::check`.
+pub fn submit(client: &Client, year: u32, day: u32, part: u32, answer: &str) -> Result<Verdict, FetchError> {
+    let form = format!("level={part}&answer={}", http::form_encode(answer));
+    let response = client.post(&format!("/{year}/day/{day}/answer"), &form)?;
+
+    match response.status {
+        200 => Ok(parse_response(&response.body)),
+        status => Err(FetchError::Status(status, response.body)),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub input_hash: u64,
+    /// Seconds since the Unix epoch.
+    pub submitted: u64,
+    pub verdict: Verdict,
+    pub answer: String,
+}
+
+/// Why an answer is not worth sending.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    Solved(String),
+    Submitted(Verdict),
+    /// The answer is at least a known too-high answer, held here.
+    AboveTooHigh(String),
+    BelowTooLow(String),
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::Solved(answer) => write!(f, "already solved, the answer was {answer}"),
+            Refusal::Submitted(verdict) => write!(f, "already submitted, it was {verdict}"),
+            Refusal::AboveTooHigh(bound) => write!(f, "{bound} was already too high"),
+            Refusal::BelowTooLow(bound) => write!(f, "{bound} was already too low"),
+        }
+    }
+}
+
+/// Every answer submitted so far, oldest first.
+#[derive(Debug, Default)]
+pub struct History {
+    pub submissions: Vec<Submission>,
+}
+
+impl History {
+    /// Reads a history file; a missing file is treated as empty.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<History> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => text.parse().map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(History::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        std::fs::write(path, self.to_string())
+    }
+
+    /// Adds a submission, if its verdict says anything about the answer.
+    pub fn record(&mut self, year: u32, day: u32, part: u32, input: &str, answer: &str, verdict: Verdict) {
+        if !verdict.is_final() {
+            return;
+        }
+
+        self.submissions.push(Submission {
+            year,
+            day,
+            part,
+            input_hash: input_hash(input),
+            submitted: SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |since| since.as_secs()),
+            verdict,
+            answer: answer.to_owned(),
+        });
+    }
+
+    /// Submissions for `day` of `year` made with `input`.
+    pub fn for_day<'a>(&'a self, year: u32, day: u32, input: &str) -> impl Iterator<Item = &'a Submission> {
+        let hash = input_hash(input);
+        self.submissions
+            .iter()
+            .filter(move |s| (s.year, s.day, s.input_hash) == (year, day, hash))
+    }
+
+    /// Whether `answer` to `part` could be right, going by earlier
+    /// submissions. Bounds only apply to whole numbers.
+    pub fn check(&self, year: u32, day: u32, part: u32, input: &str, answer: &str) -> Result<(), Refusal> {
+        let earlier: Vec<&Submission> = self.for_day(year, day, input).filter(|s| s.part == part).collect();
+
+        if let Some(right) = earlier.iter().find(|s| s.verdict == Verdict::Right) {
+            return Err(Refusal::Solved(right.answer.clone()));
+        }
+        if let Some(same) = earlier.iter().find(|s| s.answer == answer) {
+            return Err(Refusal::Submitted(same.verdict.clone()));
+        }
+
+        let Ok(value) = answer.parse::<i64>() else {
+            return Ok(());
+        };
+        for submission in earlier {
+            let Ok(bound) = submission.answer.parse::<i64>() else {
+                continue;
+            };
+            match submission.verdict {
+                Verdict::TooHigh if value >= bound => return Err(Refusal::AboveTooHigh(submission.answer.clone())),
+                Verdict::TooLow if value <= bound => return Err(Refusal::BelowTooLow(submission.answer.clone())),
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for History {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<History, ParseError> {
+        let mut history = History::default();
+
+        for line in text.lines() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.splitn(7, '\t').collect();
+            let [year, day, part, hash, submitted, verdict, answer] = fields[..] else {
+                return Err(ParseError::at(
+                    text,
+                    line,
+                    "expected year, day, part, input hash, time, verdict and answer separated by tabs",
+                ));
+            };
+
+            let verdict = match verdict {
+                "right" => Verdict::Right,
+                "wrong" => Verdict::Wrong,
+                "too-high" => Verdict::TooHigh,
+                "too-low" => Verdict::TooLow,
+                _ => return Err(ParseError::at(text, verdict, "expected right, wrong, too-high or too-low")),
+            };
+
+            history.submissions.push(Submission {
+                year: number(text, year)?,
+                day: number(text, day)?,
+                part: number(text, part)?,
+                input_hash: u64::from_str_radix(hash, 16).map_err(|_| ParseError::at(text, hash, "expected a hex input hash"))?,
+                submitted: number(text, submitted)?,
+                verdict,
+                answer: unescape(answer),
+            });
+        }
+
+        Ok(history)
+    }
+}
+
+impl fmt::Display for History {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{HEADER}")?;
+        for s in &self.submissions {
+            writeln!(
+                f,
+                "{}\t{}\t{}\t{:016x}\t{}\t{}\t{}",
+                s.year,
+                s.day,
+                s.part,
+                s.input_hash,
+                s.submitted,
+                s.verdict.name(),
+                escape(&s.answer)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// The history file that lives next to the inputs in `dir`.
+pub fn path(dir: &InputDir) -> PathBuf {
+    dir.dir().join("submissions.txt")
+}
+
+#[test]
+fn test() {
+    fn page(article: &str) -> String {
+        format!("<html><main>\n<article><p>{article}</p></article>\n</main></html>")
+    }
+
+    assert_eq!(parse_response(&page("That's the right answer!  You are <em>one gold star</em> closer.")), Verdict::Right);
+    assert_eq!(
+        parse_response(&page("That's not the right answer; your answer is too high.  If you're stuck, ...")),
+        Verdict::TooHigh
+    );
+    assert_eq!(parse_response(&page("That's not the right answer; your answer is too low.")), Verdict::TooLow);
+    assert_eq!(parse_response(&page("That's not the right answer.  If you're stuck, ...")), Verdict::Wrong);
+    assert_eq!(
+        parse_response(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 30s left to wait.")),
+        Verdict::Wait(Some(Duration::from_secs(90)))
+    );
+    assert_eq!(
+        parse_response(&page("You don't seem to be solving the right level.  Did you already complete it?")),
+        Verdict::AlreadySolved
+    );
+    assert_eq!(parse_response(&page("Something <b>new</b>")), Verdict::Unknown("Something new".to_owned()));
+
+    let input = "1\n2";
+    let mut history = History::default();
+    history.record(2022, 1, 1, input, "30", Verdict::TooHigh);
+    history.record(2022, 1, 1, input, "10", Verdict::TooLow);
+    history.record(2022, 1, 1, input, "25", Verdict::Wrong);
+    history.record(2022, 1, 1, input, "26", Verdict::Wait(None));
+    history.record(2022, 1, 2, input, "a\tb", Verdict::Right);
+    assert_eq!(history.submissions.len(), 4);
+
+    assert_eq!(history.check(2022, 1, 1, input, "20"), Ok(()));
+    assert_eq!(history.check(2022, 1, 1, input, "26"), Ok(()));
+    assert_eq!(history.check(2022, 1, 1, input, "25"), Err(Refusal::Submitted(Verdict::Wrong)));
+    assert_eq!(history.check(2022, 1, 1, input, "31"), Err(Refusal::AboveTooHigh("30".to_owned())));
+    assert_eq!(history.check(2022, 1, 1, input, "-4"), Err(Refusal::BelowTooLow("10".to_owned())));
+    assert_eq!(history.check(2022, 1, 2, input, "x"), Err(Refusal::Solved("a\tb".to_owned())));
+    assert_eq!(history.check(2022, 1, 1, "other input", "25"), Ok(()));
+
+    let reloaded: History = history.to_string().parse().unwrap();
+    assert_eq!(reloaded.submissions, history.submissions);
+    assert!(history.to_string().starts_with(HEADER));
+
+    let err = "2022\t1\t1\t00\t0\tmaybe\t5".parse::<History>().unwrap_err();
+    assert_eq!(err.to_string(), "line 1, column 15: expected right, wrong, too-high or too-low, found `maybe`");
+
+    let server = crate::mock::Server::start(move |request| match request.body.as_str() {
+        "level=1&answer=24000" => (200, page("That's the right answer!")),
+        "level=2&answer=a%20b%26c" => (200, page("That's not the right answer.")),
+        _ => (302, String::new()),
+    });
+    let root = std::env::temp_dir().join(format!("aoc-submit-{}", std::process::id()));
+    let config = crate::config::Config {
+        session: Some("good".to_owned()),
+        base_url: server.url(),
+        interval: Duration::ZERO,
+        ..Default::default()
+    };
+    let client = Client::new(config, InputDir::new(&root));
+
+    assert_eq!(submit(&client, 2022, 1, 1, "24000").unwrap(), Verdict::Right);
+    assert_eq!(submit(&client, 2022, 1, 2, "a b&c").unwrap(), Verdict::Wrong);
+    assert!(matches!(submit(&client, 2022, 1, 2, "?"), Err(FetchError::Status(302, _))));
+
+    let requests = server.requests();
+    assert_eq!(requests[0].path, "/2022/day/1/answer");
+    assert_eq!(requests[0].header("content-type"), Some("application/x-www-form-urlencoded"));
+
+    std::fs::remove_dir_all(root).unwrap();
+}
@@ -0,0 +1,535 @@
+//! Inputs encrypted at rest.
+//!
+//! Puzzle inputs are personal, so they can be kept in `vault/YYYY/dayNN.sealed`
+//! under the input directory, encrypted with a key derived from the
+//! passphrase in `$AOC_VAULT_KEY`, and committed in place of the plain
+//! files. `InputDir::load` falls back to the vault when there is no plain
+//! input and the key is set.
+//!
+//! Everything is done here, with no outside crates: PBKDF2-HMAC-SHA256
+//! turns the passphrase and the vault's random salt, kept in `vault/salt`,
+//! into a ChaCha20 key and an HMAC-SHA256 key. Each
+//! file is ChaCha20 encrypted under a nonce derived from its contents, so
+//! adding the same input again writes the same file, then authenticated
+//! with an HMAC over the year, day, nonce and ciphertext, so a wrong key
+//! or a swapped or damaged file is caught before anything is returned.
+//!
+//! The passphrase is never committed. Whoever owns the inputs gives it to
+//! anyone who should run the `real` tests by some other channel, and stores
+//! it as a secret named `AOC_VAULT_KEY` in CI. Without it, a `real` test whose
+//! input is only in the vault fails instead of skipping; `aoc vault list`
+//! shows whether every entry opens with it. New salts come from
+//! `/dev/urandom`, so a vault can only be started on a Unix-like host.
+
+use std::{
+    fmt, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use crate::input::{self, InputDir, InputError};
+
+pub const KEY_VAR: &str = "AOC_VAULT_KEY";
+
+const MAGIC: &[u8] = b"aoc vault 1\n";
+const SALT_LEN: usize = 16;
+const ITERATIONS: u32 = 600_000;
+const NONCE_LEN: usize = 12;
+const TAG_LEN: usize = 32;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VaultError {
+    /// Not a vault file, or one cut short.
+    Malformed,
+    /// The key is wrong or the file was changed or belongs to another day.
+    Rejected,
+    NotText,
+}
+
+impl fmt::Display for VaultError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VaultError::Malformed => write!(f, "not a vault file"),
+            VaultError::Rejected => write!(f, "wrong key, or the file was damaged or moved"),
+            VaultError::NotText => write!(f, "decrypted to something other than text"),
+        }
+    }
+}
+
+impl std::error::Error for VaultError {}
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct Key {
+    cipher: [u8; 32],
+    mac: [u8; 32],
+}
+
+impl fmt::Debug for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Key(..)")
+    }
+}
+
+impl Key {
+    pub fn derive(passphrase: &str, salt: &[u8]) -> Key {
+        Key::derive_with(passphrase, salt, ITERATIONS)
+    }
+
+    fn derive_with(passphrase: &str, salt: &[u8], iterations: u32) -> Key {
+        let mut derived = [0; 64];
+        pbkdf2(passphrase.as_bytes(), salt, iterations, &mut derived);
+
+        let mut key = Key { cipher: [0; 32], mac: [0; 32] };
+        key.cipher.copy_from_slice(&derived[..32]);
+        key.mac.copy_from_slice(&derived[32..]);
+        key
+    }
+
+    /// The key for the passphrase in `$AOC_VAULT_KEY` and the salt of the
+    /// vault in `dir`, or `None` if the passphrase is not set. Derived once
+    /// per passphrase and salt, since derivation is slow on purpose.
+    pub fn from_env(dir: &InputDir) -> io::Result<Option<Key>> {
+        static CACHE: Mutex<Option<(String, Vec<u8>, Key)>> = Mutex::new(None);
+
+        let Some(passphrase) = std::env::var(KEY_VAR).ok().filter(|passphrase| !passphrase.is_empty()) else {
+            return Ok(None);
+        };
+        let salt = read_salt(dir)?;
+        let mut cache = CACHE.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        match &*cache {
+            Some((cached, cached_salt, key)) if *cached == passphrase && *cached_salt == salt => Ok(Some(key.clone())),
+            _ => {
+                let key = Key::derive(&passphrase, &salt);
+                *cache = Some((passphrase, salt, key.clone()));
+                Ok(Some(key))
+            }
+        }
+    }
+}
+
+fn salt_path(dir: &InputDir) -> PathBuf {
+    dir.dir().join("vault/salt")
+}
+
+fn read_salt(dir: &InputDir) -> io::Result<Vec<u8>> {
+    let path = salt_path(dir);
+    let text = fs::read_to_string(&path).map_err(|err| io::Error::new(err.kind(), format!("{}: {err}", path.display())))?;
+    let salt = (0..text.trim().len())
+        .step_by(2)
+        .map(|i| text.trim().get(i..i + 2).and_then(|byte| u8::from_str_radix(byte, 16).ok()))
+        .collect::<Option<Vec<u8>>>()
+        .filter(|salt| salt.len() == SALT_LEN);
+    salt.ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, format!("{}: not a salt", path.display())))
+}
+
+/// Gives the vault in `dir` a random salt, unless it has one.
+pub fn init(dir: &InputDir) -> io::Result<()> {
+    let path = salt_path(dir);
+    if path.exists() {
+        return Ok(());
+    }
+
+    let mut salt = [0; SALT_LEN];
+    fs::File::open("/dev/urandom")
+        .and_then(|mut random| random.read_exact(&mut salt))
+        .map_err(|err| {
+            let message = format!("could not read /dev/urandom for a salt, the vault needs a Unix-like host: {err}");
+            io::Error::new(err.kind(), message)
+        })?;
+    fs::create_dir_all(path.parent().expect("the salt is in the vault"))?;
+    let hex: String = salt.iter().map(|b| format!("{b:02x}")).collect();
+    fs::write(path, hex + "\n")
+}
+
+/// Encrypts the input for `day` of `year`.
+pub fn seal(key: &Key, year: u32, day: u32, text: &str) -> Vec<u8> {
+    let label = label(year, day);
+    let nonce = nonce(key, year, day, text);
+
+    let mut sealed = MAGIC.to_vec();
+    sealed.extend_from_slice(&nonce);
+    let mut ciphertext = text.as_bytes().to_vec();
+    chacha20(&key.cipher, &nonce, &mut ciphertext);
+    sealed.extend_from_slice(&ciphertext);
+
+    let tag = hmac(&key.mac, &[b"tag", &label, &sealed]);
+    sealed.extend_from_slice(&tag);
+    sealed
+}
+
+/// Decrypts what `seal` wrote for `day` of `year`.
+pub fn open(key: &Key, year: u32, day: u32, sealed: &[u8]) -> Result<String, VaultError> {
+    if sealed.len() < MAGIC.len() + NONCE_LEN + TAG_LEN || !sealed.starts_with(MAGIC) {
+        return Err(VaultError::Malformed);
+    }
+
+    let (body, tag) = sealed.split_at(sealed.len() - TAG_LEN);
+    let expected = hmac(&key.mac, &[b"tag", &label(year, day), body]);
+    // Compares every byte, so the time taken says nothing about the tag.
+    if tag.iter().zip(expected).fold(0, |diff, (a, b)| diff | (a ^ b)) != 0 {
+        return Err(VaultError::Rejected);
+    }
+
+    let (nonce, ciphertext) = body[MAGIC.len()..].split_at(NONCE_LEN);
+    let mut plaintext = ciphertext.to_vec();
+    chacha20(&key.cipher, nonce.try_into().unwrap(), &mut plaintext);
+
+    String::from_utf8(plaintext).map_err(|_| VaultError::NotText)
+}
+
+/// The year and day as fixed-width bytes, so no two days share a prefix of
+/// what is hashed after them.
+fn label(year: u32, day: u32) -> [u8; 8] {
+    let mut label = [0; 8];
+    label[..4].copy_from_slice(&year.to_be_bytes());
+    label[4..].copy_from_slice(&day.to_be_bytes());
+    label
+}
+
+fn nonce(key: &Key, year: u32, day: u32, text: &str) -> [u8; NONCE_LEN] {
+    hmac(&key.mac, &[b"nonce", &label(year, day), text.as_bytes()])[..NONCE_LEN].try_into().unwrap()
+}
+
+/// Where the vault keeps the input for `day` of `year`.
+pub fn path(dir: &InputDir, year: u32, day: u32) -> PathBuf {
+    dir.dir().join(format!("vault/{year}/day{day:02}.sealed"))
+}
+
+/// Encrypts `text` into the vault, returning where it went.
+pub fn add(dir: &InputDir, key: &Key, year: u32, day: u32, text: &str) -> io::Result<PathBuf> {
+    let path = path(dir, year, day);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&path, seal(key, year, day, text))?;
+    Ok(path)
+}
+
+/// The input for `day` of `year` from the vault, normalised like any other,
+/// or `None` if the vault does not have it.
+pub fn load(dir: &InputDir, year: u32, day: u32) -> Option<Result<String, InputError>> {
+    let path = path(dir, year, day);
+    let sealed = match fs::read(&path) {
+        Ok(sealed) => sealed,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return None,
+        Err(err) => return Some(Err(InputError::Io(path, err))),
+    };
+
+    let key = match Key::from_env(dir) {
+        Ok(Some(key)) => key,
+        Ok(None) => return Some(Err(InputError::Locked(path))),
+        Err(err) => return Some(Err(InputError::Io(salt_path(dir), err))),
+    };
+    Some(
+        open(&key, year, day, &sealed)
+            .map(|text| input::normalise(&text))
+            .map_err(|err| InputError::Vault(path, err)),
+    )
+}
+
+/// Every input in the vault, as year, day and path, in order.
+pub fn list(dir: &InputDir) -> io::Result<Vec<(u32, u32, PathBuf)>> {
+    let root = dir.dir().join("vault");
+    let mut entries = vec![];
+
+    let years = match fs::read_dir(&root) {
+        Ok(years) => years,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(entries),
+        Err(err) => return Err(err),
+    };
+    for year_entry in years {
+        let year_path = year_entry?.path();
+        let Some(year) = file_name(&year_path).and_then(|name| name.parse().ok()) else {
+            continue;
+        };
+        for day_entry in fs::read_dir(&year_path)? {
+            let path = day_entry?.path();
+            let day = file_name(&path)
+                .and_then(|name| name.strip_prefix("day")?.strip_suffix(".sealed")?.parse().ok());
+            if let Some(day) = day {
+                entries.push((year, day, path));
+            }
+        }
+    }
+
+    entries.sort();
+    Ok(entries)
+}
+
+fn file_name(path: &Path) -> Option<&str> {
+    path.file_name()?.to_str()
+}
+
+fn chacha20(key: &[u8; 32], nonce: &[u8; NONCE_LEN], data: &mut [u8]) {
+    for (counter, chunk) in (1..).zip(data.chunks_mut(64)) {
+        let block = chacha20_block(key, counter, nonce);
+        for (byte, k) in chunk.iter_mut().zip(block) {
+            *byte ^= k;
+        }
+    }
+}
+
+/// The ChaCha20 block function from RFC 8439.
+fn chacha20_block(key: &[u8; 32], counter: u32, nonce: &[u8; NONCE_LEN]) -> [u8; 64] {
+    let word = |bytes: &[u8]| u32::from_le_bytes(bytes.try_into().unwrap());
+
+    let mut initial = [0u32; 16];
+    initial[..4].copy_from_slice(&[0x61707865, 0x3320646e, 0x79622d32, 0x6b206574]);
+    for i in 0..8 {
+        initial[4 + i] = word(&key[i * 4..i * 4 + 4]);
+    }
+    initial[12] = counter;
+    for i in 0..3 {
+        initial[13 + i] = word(&nonce[i * 4..i * 4 + 4]);
+    }
+
+    let mut state = initial;
+    let quarter = |s: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize| {
+        s[a] = s[a].wrapping_add(s[b]);
+        s[d] = (s[d] ^ s[a]).rotate_left(16);
+        s[c] = s[c].wrapping_add(s[d]);
+        s[b] = (s[b] ^ s[c]).rotate_left(12);
+        s[a] = s[a].wrapping_add(s[b]);
+        s[d] = (s[d] ^ s[a]).rotate_left(8);
+        s[c] = s[c].wrapping_add(s[d]);
+        s[b] = (s[b] ^ s[c]).rotate_left(7);
+    };
+    for _ in 0..10 {
+        quarter(&mut state, 0, 4, 8, 12);
+        quarter(&mut state, 1, 5, 9, 13);
+        quarter(&mut state, 2, 6, 10, 14);
+        quarter(&mut state, 3, 7, 11, 15);
+        quarter(&mut state, 0, 5, 10, 15);
+        quarter(&mut state, 1, 6, 11, 12);
+        quarter(&mut state, 2, 7, 8, 13);
+        quarter(&mut state, 3, 4, 9, 14);
+    }
+
+    let mut block = [0; 64];
+    for (i, (word, initial)) in state.iter().zip(initial).enumerate() {
+        block[i * 4..i * 4 + 4].copy_from_slice(&word.wrapping_add(initial).to_le_bytes());
+    }
+    block
+}
+
+fn pbkdf2(password: &[u8], salt: &[u8], iterations: u32, out: &mut [u8]) {
+    let hmac = Hmac::new(password);
+    for (index, chunk) in (1u32..).zip(out.chunks_mut(32)) {
+        let mut u = hmac.mac(&[salt, &index.to_be_bytes()]);
+        let mut t = u;
+        for _ in 1..iterations {
+            u = hmac.mac(&[&u]);
+            for (t, u) in t.iter_mut().zip(u) {
+                *t ^= u;
+            }
+        }
+        chunk.copy_from_slice(&t[..chunk.len()]);
+    }
+}
+
+/// HMAC-SHA256 of the concatenation of `parts`.
+fn hmac(key: &[u8], parts: &[&[u8]]) -> [u8; 32] {
+    Hmac::new(key).mac(parts)
+}
+
+/// HMAC-SHA256 with the padded key already hashed, which PBKDF2 reuses on
+/// every iteration.
+struct Hmac {
+    inner: Sha256,
+    outer: Sha256,
+}
+
+impl Hmac {
+    fn new(key: &[u8]) -> Hmac {
+        let mut block = [0; 64];
+        if key.len() > 64 {
+            block[..32].copy_from_slice(&Sha256::digest(&[key]));
+        } else {
+            block[..key.len()].copy_from_slice(key);
+        }
+
+        let mut inner = Sha256::new();
+        inner.update(&block.map(|b| b ^ 0x36));
+        let mut outer = Sha256::new();
+        outer.update(&block.map(|b| b ^ 0x5c));
+        Hmac { inner, outer }
+    }
+
+    fn mac(&self, parts: &[&[u8]]) -> [u8; 32] {
+        let mut inner = self.inner.clone();
+        for part in parts {
+            inner.update(part);
+        }
+
+        let mut outer = self.outer.clone();
+        outer.update(&inner.finish());
+        outer.finish()
+    }
+}
+
+#[derive(Clone)]
+struct Sha256 {
+    state: [u32; 8],
+    buffer: Vec<u8>,
+    length: u64,
+}
+
+impl Sha256 {
+    const K: [u32; 64] = [
+        0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5, 0xd807aa98,
+        0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174, 0xe49b69c1, 0xefbe4786,
+        0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da, 0x983e5152, 0xa831c66d, 0xb00327c8,
+        0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967, 0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13,
+        0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85, 0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819,
+        0xd6990624, 0xf40e3585, 0x106aa070, 0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a,
+        0x5b9cca4f, 0x682e6ff3, 0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7,
+        0xc67178f2,
+    ];
+
+    fn new() -> Sha256 {
+        Sha256 {
+            state: [
+                0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+            ],
+            buffer: Vec::with_capacity(64),
+            length: 0,
+        }
+    }
+
+    fn digest(parts: &[&[u8]]) -> [u8; 32] {
+        let mut sha = Sha256::new();
+        for part in parts {
+            sha.update(part);
+        }
+        sha.finish()
+    }
+
+    fn update(&mut self, mut data: &[u8]) {
+        self.length += data.len() as u64;
+
+        while !data.is_empty() {
+            let take = (64 - self.buffer.len()).min(data.len());
+            self.buffer.extend_from_slice(&data[..take]);
+            data = &data[take..];
+
+            if self.buffer.len() == 64 {
+                let block: [u8; 64] = self.buffer[..].try_into().unwrap();
+                self.compress(&block);
+                self.buffer.clear();
+            }
+        }
+    }
+
+    fn finish(mut self) -> [u8; 32] {
+        let bits = self.length * 8;
+        self.update(&[0x80]);
+        while self.buffer.len() != 56 {
+            self.update(&[0]);
+        }
+        self.update(&bits.to_be_bytes());
+
+        let mut digest = [0; 32];
+        for (i, word) in self.state.iter().enumerate() {
+            digest[i * 4..i * 4 + 4].copy_from_slice(&word.to_be_bytes());
+        }
+        digest
+    }
+
+    fn compress(&mut self, block: &[u8; 64]) {
+        let mut w = [0u32; 64];
+        for i in 0..16 {
+            w[i] = u32::from_be_bytes(block[i * 4..i * 4 + 4].try_into().unwrap());
+        }
+        for i in 16..64 {
+            let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+            let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+            w[i] = w[i - 16].wrapping_add(s0).wrapping_add(w[i - 7]).wrapping_add(s1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = self.state;
+        for (k, w) in Self::K.iter().zip(w) {
+            let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+            let ch = (e & f) ^ (!e & g);
+            let t1 = h.wrapping_add(s1).wrapping_add(ch).wrapping_add(*k).wrapping_add(w);
+            let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+            let maj = (a & b) ^ (a & c) ^ (b & c);
+            let t2 = s0.wrapping_add(maj);
+
+            (h, g, f, e, d, c, b, a) = (g, f, e, d.wrapping_add(t1), c, b, a, t1.wrapping_add(t2));
+        }
+
+        for (state, value) in self.state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+            *state = state.wrapping_add(value);
+        }
+    }
+}
+
+#[test]
+fn test() {
+    let hex = |bytes: &[u8]| bytes.iter().map(|b| format!("{b:02x}")).collect::<String>();
+
+    assert_eq!(
+        hex(&Sha256::digest(&[b"abc"])),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+    assert_eq!(
+        hex(&Sha256::digest(&[&[b'a'; 100]])),
+        hex(&Sha256::digest(&[&[b'a'; 63], &[b'a'; 37]]))
+    );
+    assert_eq!(
+        hex(&hmac(b"Jefe", &[b"what do ya want ", b"for nothing?"])),
+        "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+    );
+    let mut derived = [0; 64];
+    pbkdf2(b"passwd", b"salt", 1, &mut derived);
+    assert_eq!(
+        hex(&derived),
+        "55ac046e56e3089fec1691c22544b605f94185216dde0465e68b9d57c20dacbc\
+         49ca9cccf179b645991664b39d77ef317c71b845b1e30bd509112041d3a19783"
+    );
+
+    // RFC 8439, sections 2.3.2 and 2.4.2.
+    let key: [u8; 32] = std::array::from_fn(|i| i as u8);
+    let block = chacha20_block(&key, 1, &[0, 0, 0, 9, 0, 0, 0, 0x4a, 0, 0, 0, 0]);
+    assert_eq!(hex(&block[..16]), "10f1e7e4d13b5915500fdd1fa32071c4");
+    let mut sunscreen = b"Ladies and Gentlemen of the class of '99: If I could offer you only one tip for the future, sunscreen would be it.".to_vec();
+    chacha20(&key, &[0, 0, 0, 0, 0, 0, 0, 0x4a, 0, 0, 0, 0], &mut sunscreen);
+    assert_eq!(hex(&sunscreen[..32]), "6e2e359a2568f98041ba0728dd0d6981e97e7aec1d4360c20a27afccfd9fae0b");
+
+    // Keys for the vault itself take the full ITERATIONS; that is too slow to
+    // repeat here in a debug build.
+    let derive = |passphrase, salt| Key::derive_with(passphrase, salt, 1_000);
+    let key = derive("correct horse", b"salt");
+    let sealed = seal(&key, 2022, 1, "1000\n2000\n");
+    assert!(!sealed.windows(4).any(|window| window == b"1000"));
+    assert_eq!(seal(&key, 2022, 1, "1000\n2000\n"), sealed);
+    assert_eq!(open(&key, 2022, 1, &sealed), Ok("1000\n2000\n".to_owned()));
+
+    assert_eq!(open(&derive("wrong", b"salt"), 2022, 1, &sealed), Err(VaultError::Rejected));
+    assert_eq!(open(&key, 2022, 2, &sealed), Err(VaultError::Rejected));
+    assert_eq!(open(&derive("correct horse", b"pepper"), 2022, 1, &sealed), Err(VaultError::Rejected));
+    assert_ne!(nonce(&key, 2022, 1, "1abc"), nonce(&key, 2022, 11, "abc"));
+    let mut damaged = sealed.clone();
+    damaged[MAGIC.len() + NONCE_LEN] ^= 1;
+    assert_eq!(open(&key, 2022, 1, &damaged), Err(VaultError::Rejected));
+    assert_eq!(open(&key, 2022, 1, b"1000\n2000\n"), Err(VaultError::Malformed));
+
+    let root = std::env::temp_dir().join(format!("aoc-vault-{}", std::process::id()));
+    let dir = InputDir::new(&root);
+    init(&dir).unwrap();
+    let salt = read_salt(&dir).unwrap();
+    init(&dir).unwrap();
+    assert_eq!(read_salt(&dir).unwrap(), salt);
+    assert_ne!(salt, [0; SALT_LEN]);
+    let path = add(&dir, &key, 2022, 1, "1000\n2000\n").unwrap();
+    assert_eq!(path, root.join("vault/2022/day01.sealed"));
+    add(&dir, &key, 2021, 25, "x").unwrap();
+    assert_eq!(
+        list(&dir).unwrap(),
+        [(2021, 25, root.join("vault/2021/day25.sealed")), (2022, 1, path)]
+    );
+    assert!(load(&dir, 2022, 2).is_none());
+
+    fs::remove_dir_all(root).unwrap();
+}
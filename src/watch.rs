@@ -0,0 +1,137 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use crate::{examples, input::InputDir, Day};
+
+/// Answers from one run, keyed by where the input came from (`input` or
+/// `example <name>`) and part. Failures hold their error message.
+pub type Snapshot = BTreeMap<(String, u32), Result<String, String>>;
+
+/// Modification times of the files a run depends on; `None` for a file that
+/// does not exist, so creating or deleting one also counts as a change.
+pub type Stamps = Vec<(PathBuf, Option<SystemTime>)>;
+
+/// The files `day` depends on: its input in `dir`, or the vault's copy while
+/// there is no plain one, and every example file, answers included, in
+/// `examples_dir`.
+pub fn watched(day: &Day, dir: &InputDir, examples_dir: &Path) -> Vec<PathBuf> {
+    let mut paths = vec![dir.path(day.year, day.day)];
+    if !paths[0].exists() {
+        paths.push(crate::vault::path(dir, day.year, day.day));
+    }
+
+    if let Ok(entries) = fs::read_dir(examples_dir) {
+        let prefix = format!("day{:02}-", day.day);
+        let mut examples: Vec<PathBuf> = entries
+            .filter_map(|entry| Some(entry.ok()?.path()))
+            .filter(|path| path.file_name().and_then(|name| name.to_str()).is_some_and(|name| name.starts_with(&prefix)))
+            .collect();
+        examples.sort();
+        paths.extend(examples);
+    }
+
+    paths
+}
+
+pub fn stamps(paths: &[PathBuf]) -> Stamps {
+    paths
+        .iter()
+        .map(|path| (path.clone(), fs::metadata(path).and_then(|meta| meta.modified()).ok()))
+        .collect()
+}
+
+/// Solves both parts of `day` for its input and each of its examples.
+pub fn snapshot(day: &Day, dir: &InputDir, examples_dir: &Path) -> Snapshot {
+    let mut sources = vec![("input".to_owned(), dir.load(day.year, day.day).map_err(|err| err.to_string()))];
+
+    match examples::discover(examples_dir) {
+        Ok(found) => sources.extend(
+            found
+                .into_iter()
+                .filter(|example| example.day == day.day)
+                .map(|example| (format!("example {}", example.name), Ok(example.input))),
+        ),
+        Err(err) => sources.push(("examples".to_owned(), Err(err.to_string()))),
+    }
+
+    let mut snapshot = Snapshot::new();
+    for (source, input) in sources {
+        let parsed = input.and_then(|input| day.parse(&input).map_err(|err| err.to_string()));
+
+        for part in [1, 2] {
+            let answer = match &parsed {
+                Ok(parsed) => day.part(part, parsed).unwrap().map(|answer| answer.to_string()).map_err(|err| err.to_string()),
+                Err(err) => Err(err.clone()),
+            };
+            snapshot.insert((source.clone(), part), answer);
+        }
+    }
+
+    snapshot
+}
+
+/// Lists every answer in `new`, marked `=` if unchanged since `old`, `~` if
+/// changed (showing old and new), `+` if new, and `-` for answers that have
+/// gone.
+pub fn diff(old: &Snapshot, new: &Snapshot) -> String {
+    let mut diff = String::new();
+
+    let show = |answer: &Result<String, String>| match answer {
+        Ok(answer) if answer.contains('\n') => format!("\n{answer}\n"),
+        Ok(answer) => answer.clone(),
+        Err(err) => format!("error: {err}"),
+    };
+
+    for ((source, part), answer) in new {
+        match old.get(&(source.clone(), *part)) {
+            Some(previous) if previous == answer => writeln!(diff, "= {source} part {part}: {}", show(answer)),
+            Some(previous) => writeln!(diff, "~ {source} part {part}: {} -> {}", show(previous), show(answer)),
+            None => writeln!(diff, "+ {source} part {part}: {}", show(answer)),
+        }
+        .unwrap();
+    }
+    for ((source, part), answer) in old {
+        if !new.contains_key(&(source.clone(), *part)) {
+            writeln!(diff, "- {source} part {part}: {}", show(answer)).unwrap();
+        }
+    }
+
+    diff
+}
+
+#[test]
+fn test() {
+    let entry = |source: &str, part, answer: &str| ((source.to_owned(), part), Ok(answer.to_owned()));
+
+    let old: Snapshot = [entry("input", 1, "10"), entry("input", 2, "20"), entry("example 1", 1, "1")].into();
+    let new: Snapshot = [entry("input", 1, "10"), entry("input", 2, "21"), entry("example 2", 1, "2")].into();
+
+    assert_eq!(
+        diff(&old, &new),
+        "+ example 2 part 1: 2\n= input part 1: 10\n~ input part 2: 20 -> 21\n- example 1 part 1: 1\n"
+    );
+
+    let root = std::env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+    let examples_dir = root.join("2022/examples");
+    fs::create_dir_all(&examples_dir).unwrap();
+    fs::write(root.join("2022/day02.txt"), "A Y\nB X\nC Z\n").unwrap();
+    fs::write(examples_dir.join("day02-1.txt"), "A X").unwrap();
+    fs::write(examples_dir.join("day03-1.txt"), "ab").unwrap();
+
+    let dir = InputDir::new(&root);
+    let day02 = crate::day(2022, 2).unwrap();
+    let paths = watched(day02, &dir, &examples_dir);
+    assert_eq!(paths, [root.join("2022/day02.txt"), examples_dir.join("day02-1.txt")]);
+    assert!(stamps(&paths).iter().all(|(_, stamp)| stamp.is_some()));
+
+    let snapshot = snapshot(day02, &dir, &examples_dir);
+    assert_eq!(snapshot[&("input".to_owned(), 1)], Ok("15".to_owned()));
+    assert_eq!(snapshot[&("example 1".to_owned(), 2)], Ok("3".to_owned()));
+
+    fs::remove_dir_all(root).unwrap();
+}
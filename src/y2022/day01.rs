@@ -0,0 +1,117 @@
+use std::io::BufRead;
+
+use crate::{error::{self, Error, Result}, stream::{self, Streamed, Streaming}, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Parsed = Vec<u64>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<u64>> {
+        inventories(input)
+    }
+
+    fn part1(calories: &Vec<u64>) -> Result<u64> {
+        Ok(solve(calories).0)
+    }
+
+    fn part2(calories: &Vec<u64>) -> Result<u64> {
+        if calories.len() < 3 {
+            return Err(Error::NoAnswer("fewer than three elves".to_owned()));
+        }
+
+        Ok(solve_02(calories))
+    }
+}
+
+impl Streaming for Day01 {
+    fn stream(input: impl BufRead) -> Streamed<u64, u64> {
+        // The three largest totals so far, smallest first.
+        let mut top_three = [0; 3];
+        let mut elves = 0;
+        let mut total = 0;
+        let mut previous_blank = false;
+
+        let mut finish = |total: u64| {
+            elves += 1;
+            if total > top_three[0] {
+                top_three[0] = total;
+                top_three.sort();
+            }
+        };
+
+        stream::lines(input, |number, line| {
+            if line.is_empty() && !previous_blank {
+                finish(total);
+                total = 0;
+            } else {
+                total += error::number::<u64>(line, line).map_err(|err| err.on_line(number))?;
+            }
+            previous_blank = line.is_empty();
+            Ok(())
+        })?;
+        finish(total);
+
+        let part2 = match elves {
+            0..=2 => Err(Error::NoAnswer("fewer than three elves".to_owned())),
+            _ => Ok(top_three.iter().sum()),
+        };
+        Ok((Ok(top_three[2]), part2))
+    }
+}
+
+fn inventories(input: &str) -> Result<Vec<u64>> {
+    input.trim_end().split("\n\n").map(|inventory|{
+        let total_calorites = inventory.lines().map(|item| error::number::<u64>(input, item)).sum::<std::result::Result<u64, _>>()?;
+
+        Ok(total_calorites)
+    }).collect()
+}
+
+fn solve(calories: &[u64]) -> (u64, i32) {
+    let max = calories.iter().copied().zip(1..).max_by(|a,b| a.0.cmp(&b.0));
+
+    max.unwrap()
+}
+
+fn solve_02(calories: &[u64]) -> u64 {
+    let mut calories = calories.to_vec();
+
+    let pivot = calories.len() -3;
+    calories.select_nth_unstable(pivot);
+
+    let top_three = &mut calories.as_mut_slice()[pivot..];
+
+    top_three.sort();
+
+    top_three.iter().sum()
+}
+
+#[cfg(test)]
+const TEST_INPUT: &str = include_str!("../../input/2022/examples/day01-1.txt");
+
+#[test]
+fn day_01_part_01() {
+    assert_eq!(4, solve(&inventories(TEST_INPUT).unwrap()).1);
+    assert_eq!(24000, Day01::part1(&Day01::parse(TEST_INPUT).unwrap()).unwrap());
+
+    let err = Day01::parse("1000\n\n20x0").unwrap_err();
+    assert_eq!(err.to_string(), "parse error: line 3, column 1: expected a number, found `20x0`");
+}
+
+#[test]
+fn day_01_part_02() {
+    assert_eq!(45000, solve_02(&inventories(TEST_INPUT).unwrap()));
+}
+
+#[test]
+fn stream() {
+    let (part1, part2) = Day01::stream(TEST_INPUT.as_bytes()).unwrap();
+    assert_eq!((part1.unwrap(), part2.unwrap()), (24000, 45000));
+
+    assert!(Day01::stream("1\n\n2".as_bytes()).unwrap().1.is_err());
+    let err = Day01::stream("1000\n\n\n20".as_bytes()).unwrap_err();
+    assert_eq!(err.to_string(), Day01::parse("1000\n\n\n20").unwrap_err().to_string());
+}
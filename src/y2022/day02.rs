@@ -0,0 +1,193 @@
+use std::io::BufRead;
+
+use crate::{error::ParseError, stream::{self, Streamed, Streaming}, Solution};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Parsed = Vec<(Choice, Column)>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> crate::Result<Vec<(Choice, Column)>> {
+        Ok(strategy_guide(input)?)
+    }
+
+    fn part1(guide: &Vec<(Choice, Column)>) -> crate::Result<u32> {
+        Ok(total_score(guide))
+    }
+
+    fn part2(guide: &Vec<(Choice, Column)>) -> crate::Result<u32> {
+        Ok(new_total_score(guide))
+    }
+}
+
+impl Streaming for Day02 {
+    fn stream(input: impl BufRead) -> Streamed<u32, u32> {
+        let (mut total, mut new_total) = (0, 0);
+
+        stream::lines(input, |number, line| {
+            let (opponent_shape, column) = round(line).map_err(|err| err.on_line(number))?;
+            total += score_as_shape(opponent_shape, column);
+            new_total += score_as_outcome(opponent_shape, column);
+            Ok(())
+        })?;
+
+        Ok((Ok(total), Ok(new_total)))
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum Choice {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+#[derive(Clone, Copy)]
+enum Result {
+    Win,
+    Loose,
+    Draw,
+}
+
+// The second column of the guide, which part one and part two read differently.
+#[derive(Clone, Copy, Debug)]
+pub enum Column {
+    X,
+    Y,
+    Z,
+}
+
+fn opponent(choice: &str) -> Option<Choice> {
+    match choice {
+        "A" => Some(Choice::Rock),
+        "B" => Some(Choice::Paper),
+        "C" => Some(Choice::Scissors),
+        _ => None,
+    }
+}
+
+fn column(column: &str) -> Option<Column> {
+    match column {
+        "X" => Some(Column::X),
+        "Y" => Some(Column::Y),
+        "Z" => Some(Column::Z),
+        _ => None,
+    }
+}
+
+fn me(choice: Column) -> Choice {
+    match choice {
+        Column::X => Choice::Rock,
+        Column::Y => Choice::Paper,
+        Column::Z => Choice::Scissors,
+    }
+}
+
+fn result(result: Column) -> Result {
+    match result {
+        Column::X => Result::Loose,
+        Column::Y => Result::Draw,
+        Column::Z => Result::Win,
+    }
+}
+
+fn score(me: Choice, opponent: Choice) -> Result {
+    match (me, opponent) {
+        (Choice::Rock, Choice::Rock) => Result::Draw,
+        (Choice::Rock, Choice::Paper) => Result::Loose,
+        (Choice::Rock, Choice::Scissors) => Result::Win,
+        (Choice::Paper, Choice::Rock) => Result::Win,
+        (Choice::Paper, Choice::Paper) => Result::Draw,
+        (Choice::Paper, Choice::Scissors) => Result::Loose,
+        (Choice::Scissors, Choice::Rock) => Result::Loose,
+        (Choice::Scissors, Choice::Paper) => Result::Win,
+        (Choice::Scissors, Choice::Scissors) => Result::Draw,
+    }
+}
+
+fn get_shape(me: Choice, outcome: Result) -> Choice {
+    match (me, outcome) {
+        (Choice::Rock, Result::Win) => Choice::Paper,
+        (Choice::Rock, Result::Loose) => Choice::Scissors,
+        (Choice::Rock, Result::Draw) => Choice::Rock,
+        (Choice::Paper, Result::Win) => Choice::Scissors,
+        (Choice::Paper, Result::Loose) => Choice::Rock,
+        (Choice::Paper, Result::Draw) => Choice::Paper,
+        (Choice::Scissors, Result::Win) => Choice::Rock,
+        (Choice::Scissors, Result::Loose) => Choice::Paper,
+        (Choice::Scissors, Result::Draw) => Choice::Scissors,
+    }
+}
+
+fn round_score(me: Choice, opponent: Choice) -> u32 {
+    let result_points = match score(me, opponent) {
+        Result::Win => 6,
+        Result::Loose => 0,
+        Result::Draw => 3,
+    };
+
+    let shape_points = match me {
+        Choice::Rock => 1,
+        Choice::Paper => 2,
+        Choice::Scissors => 3,
+    };
+
+    shape_points + result_points
+}
+
+fn strategy_guide(input: &str) -> std::result::Result<Vec<(Choice, Column)>, ParseError> {
+    input
+        .lines()
+        .map(|line| round(line).map_err(|err| err.within(input, line)))
+        .collect()
+}
+
+fn round(line: &str) -> std::result::Result<(Choice, Column), ParseError> {
+    let Some((first, second)) = line.split_once(' ') else {
+        return Err(ParseError::at(line, line, "expected two columns"));
+    };
+
+    let first = opponent(first).ok_or_else(|| ParseError::at(line, first, "expected A, B or C"))?;
+    let second = column(second).ok_or_else(|| ParseError::at(line, second, "expected X, Y or Z"))?;
+
+    Ok((first, second))
+}
+
+// Part one: the second column is the shape to play.
+fn score_as_shape(opponent_shape: Choice, me_shape: Column) -> u32 {
+    round_score(me(me_shape), opponent_shape)
+}
+
+// Part two: the second column is the outcome to aim for.
+fn score_as_outcome(opponent_shape: Choice, desired_outcome: Column) -> u32 {
+    round_score(get_shape(opponent_shape, result(desired_outcome)), opponent_shape)
+}
+
+fn total_score(guide: &[(Choice, Column)]) -> u32 {
+    guide.iter().map(|&(opponent_shape, me_shape)| score_as_shape(opponent_shape, me_shape)).sum()
+}
+
+fn new_total_score(guide: &[(Choice, Column)]) -> u32 {
+    guide
+        .iter()
+        .map(|&(opponent_shape, desired_outcome)| score_as_outcome(opponent_shape, desired_outcome))
+        .sum()
+}
+
+#[test]
+fn test_part_01() {
+    let test_input = include_str!("../../input/2022/examples/day02-1.txt");
+
+    assert_eq!(15, total_score(&strategy_guide(test_input).unwrap()));
+    assert_eq!(12, new_total_score(&strategy_guide(test_input).unwrap()));
+
+    let err = strategy_guide("A Y\nB W").unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "W"));
+
+    let (part1, part2) = Day02::stream(test_input.as_bytes()).unwrap();
+    assert_eq!((part1.unwrap(), part2.unwrap()), (15, 12));
+    let err = Day02::stream("A Y\nB W".as_bytes()).unwrap_err();
+    assert_eq!(err.to_string(), "parse error: line 2, column 3: expected X, Y or Z, found `W`");
+}
@@ -0,0 +1,243 @@
+use std::io::BufRead;
+
+use crate::{
+    error::{Error, ParseError, Result},
+    stream::{self, Streamed, Streaming},
+    Solution,
+};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Parsed = Vec<String>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<String>> {
+        input
+            .lines()
+            .map(|line| {
+                validate(line).map_err(|err| err.within(input, line))?;
+                Ok(line.to_owned())
+            })
+            .collect()
+    }
+
+    fn part1(bags: &Vec<String>) -> Result<u32> {
+        rucksacks(bags)
+    }
+
+    fn part2(bags: &Vec<String>) -> Result<u32> {
+        groups(bags)
+    }
+}
+
+impl Streaming for Day03 {
+    fn stream(input: impl BufRead) -> Streamed<u32, u32> {
+        let mut rucksacks = Some(0);
+        let mut groups = Ok(0);
+        let mut group: Vec<String> = Vec::with_capacity(3);
+
+        stream::lines(input, |number, line| {
+            validate(line).map_err(|err| err.on_line(number))?;
+
+            rucksacks = rucksacks.zip(get_first_in_both(split_in_two(line))).map(|(sum, item)| sum + item);
+
+            group.push(line.to_owned());
+            if let [a, b, c] = &group[..] {
+                if let Ok(sum) = groups {
+                    groups = badge(a, b, c).map(|badge| sum + badge);
+                }
+                group.clear();
+            }
+            Ok(())
+        })?;
+
+        if !group.is_empty() && groups.is_ok() {
+            groups = Err(uneven_groups());
+        }
+
+        Ok((rucksacks.ok_or_else(no_common_item), groups))
+    }
+}
+
+fn validate(line: &str) -> std::result::Result<(), ParseError> {
+    if let Some((i, c)) = line.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+        return Err(ParseError::at(line, &line[i..i + c.len_utf8()], "expected an item a-z or A-Z"));
+    }
+    if !line.len().is_multiple_of(2) {
+        return Err(ParseError::at(line, line, "expected an even number of items"));
+    }
+
+    Ok(())
+}
+
+fn no_common_item() -> Error {
+    Error::NoAnswer("a rucksack has no item in both compartments".to_owned())
+}
+
+fn uneven_groups() -> Error {
+    Error::NoAnswer("elves do not split into groups of three".to_owned())
+}
+
+fn split_in_two(input: &str) -> (&str, &str) {
+    let size = input.len();
+    assert!(size.is_multiple_of(2));
+    let size = size / 2;
+
+    input.split_at(size)
+}
+
+fn char_value(mut c: char) -> u32 {
+    let mut value = 0;
+    if c.is_uppercase() {
+        value += 26;
+    }
+    c.make_ascii_uppercase();
+
+    let c = c as u32;
+    let a = 'A' as u32;
+    let z = 'Z' as u32;
+
+    assert!(a <= c);
+    assert!(c <= z);
+
+    value += 1;
+
+    let c = c - ('A' as u32);
+
+    value += c;
+
+    value
+}
+
+#[cfg(test)]
+fn value_char(mut value: u32) -> char {
+    let uppercase = value >= 27;
+
+    if uppercase {
+        value -= 26;
+    }
+
+    let a = 'A' as u32;
+    let z = 'Z' as u32;
+
+    value -= 1;
+
+    value += a;
+
+    assert!(value >= a);
+    assert!(z >= value);
+
+    let mut value = std::char::from_u32(value).unwrap();
+
+    if !uppercase {
+        value = value.to_ascii_lowercase();
+    }
+
+    value
+}
+
+#[test]
+fn test_char_values() {
+    assert_eq!(16, char_value('p'));
+    assert_eq!(38, char_value('L'));
+    assert_eq!(42, char_value('P'));
+    assert_eq!(20, char_value('t'));
+    assert_eq!(19, char_value('s'));
+
+    assert_eq!(value_char(16), 'p');
+    assert_eq!(value_char(38), 'L');
+    assert_eq!(value_char(42), 'P');
+    assert_eq!(value_char(20), 't');
+    assert_eq!(value_char(19), 's');
+}
+
+fn get_first_in_both(inputs: (&str, &str)) -> Option<u32> {
+    let (a, b) = inputs;
+    let mut charset = [0; 26 * 2];
+
+    for c in a.chars() {
+        let c = char_value(c);
+        charset[(c - 1) as usize] += 1;
+    }
+
+    for c in b.chars() {
+        let c = char_value(c);
+        if charset[(c - 1) as usize] > 0 {
+            return Some(c);
+        }
+    }
+
+    None
+}
+
+fn rucksacks(bags: &[String]) -> Result<u32> {
+    let sum: Option<u32> = bags
+        .iter()
+        .map(|bag| split_in_two(bag))
+        .map(get_first_in_both)
+        .sum();
+    sum.ok_or_else(no_common_item)
+}
+
+fn groups(bags: &[String]) -> Result<u32> {
+    let mut bags = bags.iter();
+    let mut answer = 0;
+
+    loop {
+        crate::cancel::check()?;
+        let elf_a = if let Some(b) = bags.next() {
+            b
+        } else {
+            return Ok(answer);
+        };
+        let (Some(elf_b), Some(elf_c)) = (bags.next(), bags.next()) else {
+            return Err(uneven_groups());
+        };
+
+        answer += badge(elf_a, elf_b, elf_c)?;
+    }
+}
+
+// The value of the one item all three elves of a group carry.
+fn badge(elf_a: &str, elf_b: &str, elf_c: &str) -> Result<u32> {
+    let mut charset = [0; 26 * 2];
+
+    for c in elf_a.chars() {
+        let c = char_value(c);
+        charset[(c - 1) as usize] |= 0b001;
+    }
+    for c in elf_b.chars() {
+        let c = char_value(c);
+        charset[(c - 1) as usize] |= 0b010;
+    }
+    for c in elf_c.chars() {
+        let c = char_value(c);
+        charset[(c - 1) as usize] |= 0b100;
+    }
+
+    (1..=52)
+        .find(|i| charset[i - 1] == 0b111)
+        .map(|i| i as u32)
+        .ok_or_else(|| Error::NoAnswer("a group has no item in common".to_owned()))
+}
+
+#[test]
+fn test_part_01() {
+    let input = include_str!("../../input/2022/examples/day03-1.txt");
+
+    assert_eq!(157, rucksacks(&Day03::parse(input).unwrap()).unwrap());
+    assert_eq!(70, groups(&Day03::parse(input).unwrap()).unwrap());
+
+    let err = Day03::parse("abAB\nab1d").unwrap_err();
+    assert_eq!(err.to_string(), "parse error: line 2, column 3: expected an item a-z or A-Z, found `1`");
+    assert!(matches!(Day03::part1(&Day03::parse("abcd").unwrap()), Err(Error::NoAnswer(_))));
+
+    let (part1, part2) = Day03::stream(input.as_bytes()).unwrap();
+    assert_eq!((part1.unwrap(), part2.unwrap()), (157, 70));
+    let (part1, part2) = Day03::stream("abcd\naa".as_bytes()).unwrap();
+    assert!(matches!((part1, part2), (Err(Error::NoAnswer(_)), Err(Error::NoAnswer(_)))));
+    let err = Day03::stream("abAB\nab1d".as_bytes()).unwrap_err();
+    assert_eq!(err.to_string(), "parse error: line 2, column 3: expected an item a-z or A-Z, found `1`");
+}
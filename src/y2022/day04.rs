@@ -0,0 +1,118 @@
+use std::{io::BufRead, ops::RangeInclusive};
+
+use crate::{
+    error::{self, ParseError},
+    stream::{self, Streamed, Streaming},
+    Solution,
+};
+
+pub struct Day04;
+
+type Pair = (RangeInclusive<u32>, RangeInclusive<u32>);
+
+impl Solution for Day04 {
+    type Parsed = Vec<Pair>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> crate::Result<Vec<Pair>> {
+        let pairs = input
+            .lines()
+            .map(|line| parse_pairs(line).map_err(|err| err.within(input, line)))
+            .collect::<Result<_, _>>()?;
+
+        Ok(pairs)
+    }
+
+    fn part1(pairs: &Vec<Pair>) -> crate::Result<u32> {
+        Ok(part1(pairs))
+    }
+
+    fn part2(pairs: &Vec<Pair>) -> crate::Result<u32> {
+        Ok(part2(pairs))
+    }
+}
+
+impl Streaming for Day04 {
+    fn stream(input: impl BufRead) -> Streamed<u32, u32> {
+        let (mut includes, mut overlaps) = (0, 0);
+
+        stream::lines(input, |number, line| {
+            let (a, b) = parse_pairs(line).map_err(|err| err.on_line(number))?;
+            includes += either_includes(&a, &b) as u32;
+            overlaps += either_overlaps(&a, &b) as u32;
+            Ok(())
+        })?;
+
+        Ok((Ok(includes), Ok(overlaps)))
+    }
+}
+
+fn parse_pairs(line: &str) -> Result<Pair, ParseError> {
+    let parse_range = |pair: &str| {
+        let Some((from, to)) = pair.split_once('-') else {
+            return Err(ParseError::at(line, pair, "expected a range like 2-4"));
+        };
+
+        let from = error::number(line, from)?;
+        let to = error::number(line, to)?;
+
+        Ok(from..=to)
+    };
+
+    let Some((a, b)) = line.split_once(',') else {
+        return Err(ParseError::at(line, line, "expected two ranges separated by a comma"));
+    };
+
+    Ok((parse_range(a)?, parse_range(b)?))
+}
+
+fn includes(a: &RangeInclusive<u32>, b: &RangeInclusive<u32>) -> bool {
+    a.start() <= b.start() && a.end() >= b.end()
+}
+
+fn overlaps(a: &RangeInclusive<u32>, b: &RangeInclusive<u32>) -> bool {
+    (b.start() <= a.start() && a.start() <= b.end()) || (b.start() <= a.end() && a.end() <= b.end())
+}
+
+fn either_overlaps(a: &RangeInclusive<u32>, b: &RangeInclusive<u32>) -> bool {
+    overlaps(a, b) || overlaps(b, a)
+}
+
+fn either_includes(a: &RangeInclusive<u32>, b: &RangeInclusive<u32>) -> bool {
+    includes(a, b) || includes(b, a)
+}
+
+fn part1(pairs: &[Pair]) -> u32 {
+    let count_of_overlaps = pairs
+        .iter()
+        .filter(|(a, b)| either_includes(a, b))
+        .count();
+
+    count_of_overlaps as u32
+}
+
+fn part2(pairs: &[Pair]) -> u32 {
+    let count_of_any_overlap = pairs
+        .iter()
+        .filter(|(a, b)| either_overlaps(a, b))
+        .count();
+
+    count_of_any_overlap as u32
+}
+
+#[test]
+fn test() {
+    let pairs = include_str!("../../input/2022/examples/day04-1.txt");
+
+    assert_eq!(2, part1(&Day04::parse(pairs).unwrap()));
+    assert_eq!(4, part2(&Day04::parse(pairs).unwrap()));
+
+    let err = Day04::parse("2-4,6-8\n2-3,4_5").unwrap_err();
+    assert_eq!(err.to_string(), "parse error: line 2, column 5: expected a range like 2-4, found `4_5`");
+
+    let (part1, part2) = Day04::stream(pairs.as_bytes()).unwrap();
+    assert_eq!((part1.unwrap(), part2.unwrap()), (2, 4));
+    let err = Day04::stream("2-4,6-8\n2-3,4_5".as_bytes()).unwrap_err();
+    assert_eq!(err.to_string(), "parse error: line 2, column 5: expected a range like 2-4, found `4_5`");
+}
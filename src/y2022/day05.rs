@@ -0,0 +1,114 @@
+use crate::{
+    error::{self, Error, ParseError, Result},
+    Solution,
+};
+
+pub struct Day05;
+
+#[derive(Debug)]
+pub struct Procedure {
+    stacks: Vec<Vec<char>>,
+    moves: Vec<(u32, u32, u32)>,
+}
+
+impl Solution for Day05 {
+    type Parsed = Procedure;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Procedure> {
+        let Some((drawing, moves)) = input.split_once("\n\n") else {
+            return Err(ParseError::at(input, input, "expected a drawing and moves separated by a blank line").into());
+        };
+
+        let stacks = parse_stacks(drawing);
+        let moves = moves
+            .lines()
+            .map(|mov| parse_move(input, mov, stacks.len()))
+            .collect::<std::result::Result<_, _>>()?;
+
+        Ok(Procedure { stacks, moves })
+    }
+
+    fn part1(procedure: &Procedure) -> Result<String> {
+        get_message(&procedure.stacks, &procedure.moves, false)
+    }
+
+    fn part2(procedure: &Procedure) -> Result<String> {
+        get_message(&procedure.stacks, &procedure.moves, true)
+    }
+}
+
+// Stacks are returned bottom first, so the top crate is the last element.
+fn parse_stacks(drawing: &str) -> Vec<Vec<char>> {
+    let mut rows = drawing.lines().rev();
+    let count = rows.next().map_or(0, |row| row.split_ascii_whitespace().count());
+
+    let mut stacks = vec![vec![]; count];
+
+    for row in rows {
+        for (stack, c) in stacks.iter_mut().zip(row.chars().skip(1).step_by(4)) {
+            if c.is_ascii_alphabetic() {
+                stack.push(c);
+            }
+        }
+    }
+
+    stacks
+}
+
+fn parse_move(input: &str, mov: &str, stack_count: usize) -> std::result::Result<(u32, u32, u32), ParseError> {
+    let parts: Vec<&str> = mov.split(' ').collect();
+    let ["move", number, "from", source_stack, "to", destination_stack] = parts[..] else {
+        return Err(ParseError::at(input, mov, "expected `move N from A to B`"));
+    };
+
+    let stack = |part: &str| {
+        let stack = error::number(input, part)?;
+        if (1..=stack_count as u32).contains(&stack) {
+            Ok(stack)
+        } else {
+            Err(ParseError::at(input, part, format!("expected a stack from 1 to {stack_count}")))
+        }
+    };
+
+    Ok((error::number(input, number)?, stack(source_stack)?, stack(destination_stack)?))
+}
+
+fn get_message(stacks: &[Vec<char>], moves: &[(u32, u32, u32)], part_two: bool) -> Result<String> {
+    let mut stacks = stacks.to_owned();
+
+    for &(number, source_stack, destination_stack) in moves {
+        crate::cancel::check()?;
+        let source = &mut stacks[(source_stack-1) as usize];
+        if source.len() < number as usize {
+            return Err(Error::NoAnswer(format!("cannot move {number} crates from stack {source_stack}")));
+        }
+        let mut crates: Vec<char> = (0..number).map(|_i| source.pop().unwrap()).collect();
+
+        if part_two {
+            crates.reverse();
+        }
+
+        stacks.get_mut((destination_stack-1) as usize).unwrap().append(&mut crates);
+    }
+
+    let result:String = stacks.iter().map(|stack| stack.last().unwrap_or(&' ')).collect();
+
+    Ok(result)
+}
+
+#[test]
+fn test() {
+    let input = include_str!("../../input/2022/examples/day05-1.txt");
+
+    let procedure = Day05::parse(input).unwrap();
+
+    assert_eq!(procedure.stacks, [vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']]);
+
+    assert_eq!("CMZ", Day05::part1(&procedure).unwrap());
+    assert_eq!("MCD", Day05::part2(&procedure).unwrap());
+
+    let err = Day05::parse(&input.replace("to 3", "to 4")).unwrap_err();
+    assert_eq!(err.to_string(), "parse error: line 7, column 18: expected a stack from 1 to 3, found `4`");
+}
@@ -0,0 +1,209 @@
+use std::io::BufRead;
+
+use crate::{
+    error::{Error, ParseError, Result},
+    solution::Variant,
+    stream::{self, Streamed, Streaming},
+    Solution,
+};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Parsed = String;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    const PART1_VARIANTS: &'static [Variant<String, u32>] =
+        &[("sliding", |input| marker(input, 4).ok_or_else(no_packet_marker))];
+    const PART2_VARIANTS: &'static [Variant<String, u32>] =
+        &[("sliding", |input| marker(input, 14).ok_or_else(no_message_marker))];
+
+    fn parse(input: &str) -> Result<String> {
+        let input = input.trim_end();
+
+        if let Some((i, c)) = input.char_indices().find(|(_, c)| !c.is_ascii_lowercase()) {
+            return Err(ParseError::at(input, &input[i..i + c.len_utf8()], "expected a letter a-z").into());
+        }
+
+        Ok(input.to_owned())
+    }
+
+    fn part1(input: &String) -> Result<u32> {
+        part1(input)?.ok_or_else(no_packet_marker)
+    }
+
+    fn part2(input: &String) -> Result<u32> {
+        part2(input)?.ok_or_else(no_message_marker)
+    }
+}
+
+impl Streaming for Day06 {
+    /// Reads byte by byte rather than by line, since the whole input is one
+    /// line.
+    fn stream(input: impl BufRead) -> Streamed<u32, u32> {
+        let (mut packet, mut message) = (Marker::new(4), Marker::new(14));
+        // Trailing whitespace is fine, like in `parse`; anything after it is
+        // reported at the first whitespace byte.
+        let mut whitespace = None;
+
+        for (i, byte) in input.bytes().enumerate() {
+            let byte = byte.map_err(stream::read_error)?;
+
+            let (i, byte) = match (byte, whitespace) {
+                (b'a'..=b'z', None) => {
+                    packet.push(i, byte);
+                    message.push(i, byte);
+                    continue;
+                }
+                (b'\n' | b'\r' | b' ' | b'\t', _) => {
+                    whitespace = whitespace.or(Some((i, byte)));
+                    continue;
+                }
+                (_, Some(first)) => first,
+                (byte, None) => (i, byte),
+            };
+
+            return Err(ParseError {
+                line: 1,
+                column: i + 1,
+                text: String::from_utf8_lossy(&[byte]).into_owned(),
+                message: "expected a letter a-z".to_owned(),
+            }
+            .into());
+        }
+
+        Ok((packet.found.ok_or_else(no_packet_marker), message.found.ok_or_else(no_message_marker)))
+    }
+}
+
+fn no_packet_marker() -> Error {
+    Error::NoAnswer("no start-of-packet marker".to_owned())
+}
+
+fn no_message_marker() -> Error {
+    Error::NoAnswer("no start-of-message marker".to_owned())
+}
+
+fn has_repetition(input: &str) -> bool {
+    let mut charset = [0;26];
+
+    input.chars().for_each(|c| charset[c as usize - ('a' as usize)] += 1);
+
+    charset.iter().any(|v|*v > 1)
+}
+
+#[test]
+fn test_has_rep() {
+    assert!(has_repetition("thisstringhasrep"));
+    assert!(!has_repetition("abcd"));
+}
+
+fn part1(input: &str) -> Result<Option<u32>> {
+    let windows = 0..input.len().saturating_sub(3);
+
+    let windows = windows.map(|start| start..start+4);
+
+    let windows = windows.map(|range| &input[range]);
+
+    first_distinct(windows.zip(4..))
+}
+
+fn part2(input: &str) -> Result<Option<u32>> {
+    let windows = 0..input.len().saturating_sub(13);
+
+    let windows = windows.map(|start| start..start+14);
+
+    let windows = windows.map(|range| &input[range]);
+
+    first_distinct(windows.zip(14..))
+}
+
+// The position after the first window with no letter in it twice.
+fn first_distinct<'a>(windows: impl Iterator<Item = (&'a str, u32)>) -> Result<Option<u32>> {
+    for (window, idx) in windows {
+        crate::cancel::check()?;
+        if !has_repetition(window) {
+            return Ok(Some(idx));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Position just after the first `len` distinct letters in a row.
+fn marker(input: &str, len: usize) -> Option<u32> {
+    let mut marker = Marker::new(len);
+
+    for (i, c) in input.bytes().enumerate() {
+        marker.push(i, c);
+        if marker.found.is_some() {
+            break;
+        }
+    }
+
+    marker.found
+}
+
+/// Looks for `len` distinct letters in a row one letter at a time, moving
+/// the window's start past the previous copy of each letter as it comes in.
+struct Marker {
+    len: usize,
+    last_seen: [Option<usize>; 26],
+    start: usize,
+    found: Option<u32>,
+}
+
+impl Marker {
+    fn new(len: usize) -> Marker {
+        Marker { len, last_seen: [None; 26], start: 0, found: None }
+    }
+
+    fn push(&mut self, i: usize, c: u8) {
+        if self.found.is_some() {
+            return;
+        }
+
+        let letter = (c - b'a') as usize;
+        if let Some(previous) = self.last_seen[letter] {
+            self.start = self.start.max(previous + 1);
+        }
+        self.last_seen[letter] = Some(i);
+
+        if i + 1 - self.start == self.len {
+            self.found = Some(i as u32 + 1);
+        }
+    }
+}
+
+#[test]
+fn test() {
+    assert_eq!(Some(7), part1("mjqjpqmgbljsphdztnvjfqwrcgsmlb").unwrap());
+    assert_eq!(Some(5), part1("bvwbjplbgvbhsrlpgdmjqwftvncz").unwrap());
+    assert_eq!(Some(6), part1("nppdvjthqldpwncqszvftbrmjlhg").unwrap());
+    assert_eq!(Some(10), part1("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg").unwrap());
+    assert_eq!(Some(11), part1("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw").unwrap());
+
+    assert_eq!(Some(19), part2("mjqjpqmgbljsphdztnvjfqwrcgsmlb").unwrap());
+    assert_eq!(Some(23), part2("bvwbjplbgvbhsrlpgdmjqwftvncz").unwrap());
+    assert_eq!(Some(23), part2("nppdvjthqldpwncqszvftbrmjlhg").unwrap());
+    assert_eq!(Some(29), part2("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg").unwrap());
+    assert_eq!(Some(26), part2("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw").unwrap());
+
+    assert_eq!(None, part1("abab").unwrap());
+    assert_eq!(Some(4), part1("abcd").unwrap());
+    assert_eq!(marker("abab", 4), None);
+    assert_eq!(marker("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 14), Some(26));
+    assert!(matches!(Day06::part2(&Day06::parse("abcd").unwrap()), Err(Error::NoAnswer(_))));
+
+    let err = Day06::parse("abcD").unwrap_err();
+    assert_eq!(err.to_string(), "parse error: line 1, column 4: expected a letter a-z, found `D`");
+
+    let (part1, part2) = Day06::stream("mjqjpqmgbljsphdztnvjfqwrcgsmlb\r\n".as_bytes()).unwrap();
+    assert_eq!((part1.unwrap(), part2.unwrap()), (7, 19));
+    assert!(Day06::stream("abcdabcd".as_bytes()).unwrap().1.is_err());
+    let err = Day06::stream("ab\ncd".as_bytes()).unwrap_err();
+    assert_eq!(err.to_string(), Day06::parse("ab\ncd").unwrap_err().to_string());
+    let err = Day06::stream("abcD".as_bytes()).unwrap_err();
+    assert_eq!(err.to_string(), "parse error: line 1, column 4: expected a letter a-z, found `D`");
+}
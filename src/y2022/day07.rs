@@ -0,0 +1,249 @@
+use crate::{
+    error::{self, Error, ParseError, Result},
+    Solution,
+};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Parsed = Node;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Node> {
+        Ok(Node::parse(input)?)
+    }
+
+    fn part1(node: &Node) -> Result<usize> {
+        part1(node)
+    }
+
+    fn part2(node: &Node) -> Result<usize> {
+        part2(node)?.ok_or_else(|| Error::NoAnswer("the filesystem is larger than the disk".to_owned()))
+    }
+}
+
+/// A directory tree rebuilt from a terminal session. `size` of a directory
+/// is the total of everything below it, filled in by `Node::parse`.
+#[derive(Debug)]
+pub enum Node {
+    Dir{ name: String, size: Option<usize>, content: Vec<Node>},
+    File{ name: String, size: usize},
+}
+
+impl Node {
+    /// Replays the `cd` and `ls` output in `input` into a tree rooted at `/`.
+    pub fn parse(input: &str) -> std::result::Result<Node, ParseError> {
+        let mut node = parse(input)?;
+
+        compute_sizes(&mut node);
+
+        Ok(node)
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            Node::Dir { name, ..} => name,
+            Node::File { name, ..} => name,
+        }
+    }
+
+    /// Uses the cached size of a directory if there is one.
+    pub fn size(&self) -> usize {
+        match self {
+            Node::Dir { size: Some(size), ..} => *size,
+            Node::Dir { content, ..} => content.iter().map(Node::size).sum(),
+            Node::File { size, ..} => *size,
+        }
+    }
+
+    /// The sizes of this directory and every directory below it.
+    pub fn directory_sizes(&self) -> Result<Vec<usize>> {
+        let mut sizes = vec![];
+        directory_sizes(self, &mut sizes)?;
+        Ok(sizes)
+    }
+
+    fn create_directories(&mut self, path: &[String]) -> Option<&mut Node> {
+        if let Node::File { .. } = self {
+            return None;
+        }
+        if path.is_empty() {
+            return Some(self);
+        }
+
+        let subdir_name = path.first().unwrap();
+
+        match self {
+            Node::Dir { content ,..} => {
+                if content.iter_mut().find(|child| child.name() == subdir_name).is_none() {
+                    content.push(Node::Dir{name: subdir_name.to_owned(), size: None, content: vec![] });
+                }
+                let subdir = content.iter_mut().find(|child| child.name() == subdir_name).unwrap();
+                subdir.create_directories(&path[1..])
+            }
+            Node::File { .. } => None,
+        }
+    }
+
+    // Returns false if `name` is already a directory.
+    fn set_file_size(&mut self, name: &str, new_size: usize) -> bool {
+        match self {
+            Node::Dir { content ,..} => {
+                match content.iter_mut().find(|child| child.name() == name) {
+                    Some(child) => match child {
+                        Node::Dir { .. } => return false,
+                        Node::File { size,  .. } => {*size = new_size;},
+                    },
+                    None => {
+                        content.push(Node::File{name: name.to_owned(), size: new_size});
+                    }
+                };
+                true
+            }
+            Node::File { .. } => false,
+        }
+    }
+}
+
+fn parse(input: &str) -> std::result::Result<Node, ParseError> {
+    let mut node = Node::Dir { name:"/".to_owned(), content: vec![], size: None};
+    let mut cwd = vec![];
+    let mut cwd_node = &mut node;
+    for line in input.lines() {
+        if let Some(command) = line.strip_prefix("$ ") {
+            if let Some(argument) = command.strip_prefix("cd ") {
+                if argument == ".." {
+                    cwd.pop();
+                } else if argument == "/" {
+                    cwd.clear();
+                } else {
+                    cwd.push(argument.to_owned());
+                }
+
+                cwd_node = match node.create_directories(&cwd) {
+                    Some(cwd_node) => cwd_node,
+                    None => return Err(ParseError::at(input, argument, "cannot cd into a file")),
+                };
+            } else if command != "ls" {
+                return Err(ParseError::at(input, command, "expected `cd` or `ls`"));
+            }
+        } else if line.starts_with("dir ") {
+            // ignore
+        } else {
+            let Some((size, name)) = line.split_once(' ') else {
+                return Err(ParseError::at(input, line, "expected a file size and name"));
+            };
+            let size: usize = error::number(input, size)?;
+            if !cwd_node.set_file_size(name, size) {
+                return Err(ParseError::at(input, name, "expected a file, found a directory"));
+            }
+        }
+    }
+
+    Ok(node)
+}
+
+fn compute_sizes(node: &mut Node) -> usize {
+    match node {
+        Node::Dir { size, content, .. } => {
+            let content_size = content.iter_mut().map(compute_sizes).sum();
+
+            *size = Some(content_size);
+
+            content_size
+        },
+        Node::File { size, .. } => *size,
+    }
+}
+
+fn directory_sizes(node: &Node, sizes: &mut Vec<usize>) -> Result<()> {
+    if let Node::Dir { size, content, .. } = node {
+        crate::cancel::check()?;
+        sizes.push(size.unwrap());
+
+        for node in content {
+            directory_sizes(node, sizes)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn part1(node: &Node) -> Result<usize> {
+    let mut sizes = Vec::<usize>::new();
+    directory_sizes(node, &mut sizes)?;
+
+    Ok(sizes.iter().filter(|size| **size <= 100000).sum())
+}
+
+fn part2(node: &Node) -> Result<Option<usize>> {
+    let mut sizes = Vec::<usize>::new();
+    directory_sizes(node, &mut sizes)?;
+
+    let root_size = node.size();
+    let capacity: usize = 70000000;
+
+    let Some(free_space) = capacity.checked_sub(root_size) else {
+        return Ok(None);
+    };
+
+    let required_min = 30000000usize.saturating_sub(free_space);
+
+    sizes.sort();
+
+    Ok(sizes.iter().find(|&&val| val >= required_min ).copied())
+}
+
+#[test]
+fn test() {
+    let mut node = Node::Dir{name: "/".to_owned(), size: None, content: vec![
+        Node::Dir{name:"a".to_owned(), size: None, content: vec![
+            Node::File{name:"small".to_owned(), size: 1000},
+            Node::File{name:"small_2".to_owned(), size: 1000},
+        ]},
+        Node::File{name:"small".to_owned(), size: 1000},
+        Node::File{name:"small_2".to_owned(), size: 1000},
+    ]};
+
+    compute_sizes(&mut node);
+
+    assert_eq!(if let Node::Dir{size, .. } = node {size.unwrap()} else {0}, 4000);
+
+    let input = include_str!("../../input/2022/examples/day07-1.txt");
+
+    let mut node = parse(input).unwrap();
+
+    compute_sizes(&mut node);
+
+    let mut sizes = Vec::<usize>::new();
+    directory_sizes(&node, &mut sizes).unwrap();
+
+    let total:usize = sizes.iter().filter(|size| **size <= 100000).sum();
+    assert_eq!(95437, total);
+
+    let root_size = node.size();
+    let capacity = 70000000;
+
+    let free_space = capacity - root_size;
+
+    assert_eq!(21618835, free_space);
+
+    let required_min = 30000000 - free_space;
+
+    assert_eq!(8381165, required_min);
+
+    sizes.sort();
+
+    let &value = sizes.iter().find(|&&val| val >= required_min ).unwrap();
+
+    assert_eq!(24933642, value);
+    assert_eq!(Some(24933642), part2(&node).unwrap());
+
+    let node = Node::parse(input).unwrap();
+    assert_eq!((node.name(), node.size()), ("/", 48381165));
+    assert_eq!(node.directory_sizes().unwrap().len(), 4);
+
+    let err = Day07::parse("$ cd /\n$ ls\n12 a\n$ cd a").unwrap_err();
+    assert_eq!(err.to_string(), "parse error: line 4, column 6: cannot cd into a file, found `a`");
+}
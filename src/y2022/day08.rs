@@ -0,0 +1,249 @@
+use std::collections::HashSet;
+
+use crate::{error::{ParseError, Result}, solution::Variant, Solution};
+
+pub struct Day08;
+
+#[derive(Debug)]
+pub struct Forest {
+    trees: String,
+    size: usize,
+}
+
+impl Solution for Day08 {
+    type Parsed = Forest;
+    type Part1 = u32;
+    type Part2 = i32;
+
+    const PART1_VARIANTS: &'static [Variant<Forest, u32>] = &[("naive", part1_naive)];
+    const PART2_VARIANTS: &'static [Variant<Forest, i32>] = &[("stack", part2_stack)];
+
+    fn parse(input: &str) -> Result<Forest> {
+        let trees = input.trim_end();
+        let size = trees.lines().count();
+
+        for line in trees.lines() {
+            if let Some((i, c)) = line.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+                return Err(ParseError::at(input, &line[i..i + c.len_utf8()], "expected a tree height 0-9").into());
+            }
+            if line.len() != size {
+                return Err(ParseError::at(input, line, format!("expected a square grid of {size} trees per row")).into());
+            }
+        }
+
+        Ok(Forest { trees: trees.to_owned(), size })
+    }
+
+    fn part1(forest: &Forest) -> Result<u32> {
+        part1(&forest.trees, forest.size)
+    }
+
+    fn part2(forest: &Forest) -> Result<i32> {
+        part2(&forest.trees, forest.size as i32)
+    }
+}
+
+fn part2_compute_scenic_score(input: &[u8], size: i32, x: i32, y: i32) -> i32 {
+    part2_compute_view_distance(input, size, x, y, 1, 0)
+        * part2_compute_view_distance(input, size, x, y, 0, 1)
+        * part2_compute_view_distance(input, size, x, y, -1, 0)
+        * part2_compute_view_distance(input, size, x, y, 0, -1)
+}
+
+fn part2_compute_view_distance(input: &[u8], size: i32, x: i32, y: i32, dx: i32, dy: i32) -> i32 {
+    let get = |x, y| (input.get((y * (size + 1) + x) as usize).unwrap() - b'0') as i32;
+
+    let my_height = get(x, y);
+    for step in 1..=size {
+        let next_x = x + dx * step;
+        if next_x < 0 || next_x >= size {
+            return step-1;
+        }
+        let next_y = y + dy * step;
+        if next_y < 0 || next_y >= size {
+            return step-1;
+        }
+        if my_height <= get(next_x, next_y) {
+            return step;
+        }
+    }
+    panic!();
+}
+fn part2(input: &str, size: i32) -> Result<i32> {
+    let input = input.as_bytes();
+
+    let mut max_score = 0;
+    for x in 0..size {
+        crate::cancel::check()?;
+        for y in 0..size {
+            let score = part2_compute_scenic_score(input, size, x, y);
+            if score > max_score {
+                max_score = score;
+            }
+        }
+    }
+
+    Ok(max_score)
+}
+
+fn part1(input: &str, size: usize) -> Result<u32> {
+    let input = input.as_bytes();
+    let get = |x, y| (input.get(y * (size + 1) + x).unwrap() - b'0') as i32;
+
+    let mut set: HashSet<(usize, usize)> = HashSet::new();
+
+    for y in 0..size {
+        crate::cancel::check()?;
+        let mut height = -1;
+        for x in 0..size {
+            if get(x, y) > height {
+                height = get(x, y);
+                set.insert((x, y));
+            }
+            if height == 9 {
+                break;
+            }
+        }
+    }
+    for y in 0..size {
+        crate::cancel::check()?;
+        let mut height = -1;
+        for x in (0..size).rev() {
+            if get(x, y) > height {
+                height = get(x, y);
+                set.insert((x, y));
+            }
+            if height == 9 {
+                break;
+            }
+        }
+    }
+    for x in 0..size {
+        crate::cancel::check()?;
+        let mut height = -1;
+        for y in 0..size {
+            if get(x, y) > height {
+                height = get(x, y);
+                set.insert((x, y));
+            }
+            if height == 9 {
+                break;
+            }
+        }
+    }
+    for x in 0..size {
+        crate::cancel::check()?;
+        let mut height = -1;
+        for y in (0..size).rev() {
+            if get(x, y) > height {
+                height = get(x, y);
+                set.insert((x, y));
+            }
+            if height == 9 {
+                break;
+            }
+        }
+    }
+
+    if crate::log::enabled(2022, 8, crate::log::Level::Debug) {
+        let visible: Vec<String> = (0..size)
+            .map(|y| (0..size).map(|x| if set.contains(&(x, y)) { 'X' } else { '#' }).collect())
+            .collect();
+        crate::debug!(2022, 8, "visible trees marked X:\n{}", visible.join("\n"));
+    }
+    Ok(set.len().try_into().unwrap())
+}
+
+/// Checks every tree against every tree between it and each edge.
+fn part1_naive(forest: &Forest) -> Result<u32> {
+    let (trees, size) = (forest.trees.as_bytes(), forest.size);
+    let get = |x: usize, y: usize| trees[y * (size + 1) + x];
+
+    let mut count = 0;
+    for y in 0..size {
+        crate::cancel::check()?;
+        for x in 0..size {
+            let height = get(x, y);
+            let visible = (0..x).all(|i| get(i, y) < height)
+                || (x + 1..size).all(|i| get(i, y) < height)
+                || (0..y).all(|j| get(x, j) < height)
+                || (y + 1..size).all(|j| get(x, j) < height);
+            if visible {
+                count += 1;
+            }
+        }
+    }
+
+    Ok(count)
+}
+
+/// Finds how far every tree sees in each direction with one pass along each
+/// row and column either way, instead of scanning out from every tree.
+fn part2_stack(forest: &Forest) -> Result<i32> {
+    let (trees, size) = (forest.trees.as_bytes(), forest.size);
+    let index = |x: usize, y: usize| y * (size + 1) + x;
+
+    let mut scores = vec![1; trees.len()];
+    for line in 0..size {
+        crate::cancel::check()?;
+        let row: Vec<usize> = (0..size).map(|x| index(x, line)).collect();
+        let column: Vec<usize> = (0..size).map(|y| index(line, y)).collect();
+        for mut line in [row, column] {
+            multiply_view_distances(trees, &line, &mut scores);
+            line.reverse();
+            multiply_view_distances(trees, &line, &mut scores);
+        }
+    }
+
+    let grid = (0..size).flat_map(|y| (0..size).map(move |x| index(x, y)));
+    Ok(grid.map(|i| scores[i]).max().unwrap_or(0))
+}
+
+/// Multiplies each tree's score in `scores` by how far it sees back along
+/// `line`, the indices of a row or column in `trees`.
+fn multiply_view_distances(trees: &[u8], line: &[usize], scores: &mut [i32]) {
+    // Positions along `line` of the trees no later tree has been as tall as.
+    let mut blockers: Vec<usize> = vec![];
+
+    for (i, &tree) in line.iter().enumerate() {
+        while blockers.last().is_some_and(|&blocker| trees[line[blocker]] < trees[tree]) {
+            blockers.pop();
+        }
+        let distance = blockers.last().map_or(i, |&blocker| i - blocker);
+        scores[tree] *= distance as i32;
+        blockers.push(i);
+    }
+}
+
+#[test]
+fn test() {
+    let input = include_str!("../../input/2022/examples/day08-1.txt");
+
+    let tree_count = part1(input, 5).unwrap();
+    assert_eq!(21, tree_count);
+
+    {
+        let input = input.as_bytes();
+
+        assert_eq!(part2_compute_view_distance(input, 5, 2, 1, 0, -1), 1);
+        assert_eq!(part2_compute_view_distance(input, 5, 2, 1, -1, 0), 1);
+        assert_eq!(part2_compute_view_distance(input, 5, 2, 1, 1, 0), 2);
+        assert_eq!(part2_compute_view_distance(input, 5, 2, 1, 0, 1), 2);
+
+        assert_eq!(part2_compute_view_distance(input, 5, 2, 3, 0, -1), 2);
+        assert_eq!(part2_compute_view_distance(input, 5, 2, 3, -1, 0), 2);
+        assert_eq!(part2_compute_view_distance(input, 5, 2, 3, 1, 0), 2);
+        assert_eq!(part2_compute_view_distance(input, 5, 2, 3, 0, 1), 1);
+
+        assert_eq!(part2_compute_scenic_score(input, 5, 2, 1), 4);
+    }
+
+    assert_eq!(part2(input, 5).unwrap(), 8);
+
+    let forest = Day08::parse(input).unwrap();
+    assert_eq!(part1_naive(&forest).unwrap(), 21);
+    assert_eq!(part2_stack(&forest).unwrap(), 8);
+
+    let err = Day08::parse("123\n4x6\n789").unwrap_err();
+    assert_eq!(err.to_string(), "parse error: line 2, column 2: expected a tree height 0-9, found `x`");
+}
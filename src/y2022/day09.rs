@@ -0,0 +1,191 @@
+use std::{collections::HashSet, io::BufRead, str::FromStr, cmp::max};
+
+use crate::{error::{self, ParseError}, stream::{self, Streamed, Streaming}, Pos2d, Solution};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Parsed = Vec<Instruction>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> crate::Result<Vec<Instruction>> {
+        let instructions = input
+            .lines()
+            .map(|line| line.parse().map_err(|err: ParseError| err.within(input, line)))
+            .collect::<Result<_, _>>()?;
+
+        Ok(instructions)
+    }
+
+    fn part1(instructions: &Vec<Instruction>) -> crate::Result<i32> {
+        part1(instructions)
+    }
+
+    fn part2(instructions: &Vec<Instruction>) -> crate::Result<i32> {
+        part2(instructions)
+    }
+}
+
+impl Streaming for Day09 {
+    /// Memory grows with the number of positions visited, not with the
+    /// length of the input.
+    fn stream(input: impl BufRead) -> Streamed<i32, i32> {
+        let (mut short, mut long) = (Rope::<1>::new(), Rope::<9>::new());
+
+        stream::lines(input, |number, line| {
+            let instruction: Instruction = line.parse().map_err(|err: ParseError| err.on_line(number))?;
+            crate::trace!(2022, 9, "{instruction:?}");
+            short.apply(instruction)?;
+            long.apply(instruction)?;
+            Ok(())
+        })?;
+
+        Ok((Ok(short.visited()), Ok(long.visited())))
+    }
+}
+
+/// Where a knot at `tail` moves to after the knot ahead of it moved to `head`.
+fn follow(tail: Pos2d, head: Pos2d) -> Pos2d {
+    let dx = tail.x - head.x;
+    let dy = tail.y - head.y;
+
+    if max(dy.abs(),dx.abs()) < 2 {
+        return tail;
+    }
+
+    // One step towards the head on each axis it is off by, which is a
+    // diagonal step whenever it is off by both.
+    Pos2d::new(tail.x - dx.signum(), tail.y - dy.signum())
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum Instruction {
+    Up(usize),
+    Down(usize),
+    Left(usize),
+    Right(usize),
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, ParseError> {
+        let Some((direction, amount)) = line.split_once(' ') else {
+            return Err(ParseError::at(line, line, "expected a direction and an amount"));
+        };
+        let amount = error::number(line, amount)?;
+
+        match direction {
+            "U" => Ok(Instruction::Up(amount)),
+            "D" => Ok(Instruction::Down(amount)),
+            "L" => Ok(Instruction::Left(amount)),
+            "R" => Ok(Instruction::Right(amount)),
+            _ => Err(ParseError::at(line, direction, "expected U, D, L or R")),
+        }
+    }
+}
+
+/// A head and `N` knots, each following the one before, tracking every
+/// position the last knot visits.
+struct Rope<const N: usize> {
+    head: Pos2d,
+    tails: [Pos2d; N],
+    positions: HashSet<Pos2d>,
+}
+
+impl<const N: usize> Rope<N> {
+    fn new() -> Rope<N> {
+        Rope {
+            head: Pos2d::default(),
+            tails: [Pos2d::default(); N],
+            positions: HashSet::new(),
+        }
+    }
+
+    fn apply(&mut self, instruction: Instruction) -> crate::Result<()> {
+        let (op, amount): (fn(Pos2d) -> Pos2d, usize) = match instruction {
+            Instruction::Right(amount) => (Pos2d::right, amount),
+            Instruction::Left(amount) => (Pos2d::left, amount),
+            Instruction::Up(amount) => (Pos2d::up, amount),
+            Instruction::Down(amount) => (Pos2d::down, amount),
+        };
+
+        for _ in 0..amount {
+            crate::cancel::check()?;
+            self.head = op(self.head);
+            let mut prev_tail = self.head;
+            for tail in self.tails.iter_mut() {
+                *tail = follow(*tail, prev_tail);
+                prev_tail = *tail;
+            }
+            self.positions.insert(*self.tails.last().unwrap());
+        }
+
+        Ok(())
+    }
+
+    fn visited(&self) -> i32 {
+        crate::debug!(2022, 9, "tail visited:\n{}", render(&self.positions));
+
+        self.positions.len() as i32
+    }
+}
+
+fn part1(instructions: &[Instruction]) -> crate::Result<i32> {
+    let mut rope = Rope::<1>::new();
+
+    for &instruction in instructions {
+        crate::trace!(2022, 9, "{instruction:?}");
+        rope.apply(instruction)?;
+    }
+
+    Ok(rope.visited())
+}
+
+fn part2(instructions: &[Instruction]) -> crate::Result<i32> {
+    let mut rope = Rope::<9>::new();
+
+    for &instruction in instructions {
+        crate::trace!(2022, 9, "{instruction:?}");
+        rope.apply(instruction)?;
+    }
+
+    Ok(rope.visited())
+}
+
+/// Draws `positions` as `#` within their bounding box, the start as `s`.
+fn render(positions: &HashSet<Pos2d>) -> String {
+    let (min_x, max_x) = (positions.iter().map(|p| p.x).min().unwrap_or(0), positions.iter().map(|p| p.x).max().unwrap_or(0));
+    let (min_y, max_y) = (positions.iter().map(|p| p.y).min().unwrap_or(0), positions.iter().map(|p| p.y).max().unwrap_or(0));
+
+    (min_y..=max_y)
+        .map(|y| {
+            (min_x..=max_x)
+                .map(|x| match (x, y) {
+                    (0, 0) => 's',
+                    _ if positions.contains(&Pos2d { x, y }) => '#',
+                    _ => '.',
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+#[test]
+fn test() {
+    let input = include_str!("../../input/2022/examples/day09-1.txt");
+
+    assert_eq!(Day09::part1(&Day09::parse(input).unwrap()).unwrap(), 13);
+    assert_eq!(Day09::part2(&Day09::parse(input).unwrap()).unwrap(), 1);
+
+    let err = Day09::parse("R 4\nX 4").unwrap_err();
+    assert_eq!(err.to_string(), "parse error: line 2, column 1: expected U, D, L or R, found `X`");
+    let err = Day09::parse("R 4\nU -1").unwrap_err();
+    assert_eq!(err.to_string(), "parse error: line 2, column 3: expected a number, found `-1`");
+
+    let (part1, part2) = Day09::stream(input.as_bytes()).unwrap();
+    assert_eq!((part1.unwrap(), part2.unwrap()), (13, 1));
+    let err = Day09::stream("R 4\n\nU -1".as_bytes()).unwrap_err();
+    assert_eq!(err.to_string(), "parse error: line 2, column 1: expected a direction and an amount, found nothing");
+}
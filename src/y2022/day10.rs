@@ -0,0 +1,192 @@
+use std::{io::BufRead, str::FromStr};
+
+use crate::{error::{self, ParseError}, stream::{self, Streamed, Streaming}, Answer, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed = Vec<Instruction>;
+    type Part1 = i32;
+    type Part2 = Answer;
+
+    fn parse(input: &str) -> crate::Result<Vec<Instruction>> {
+        let program = input
+            .lines()
+            .map(|line| line.parse().map_err(|err: ParseError| err.within(input, line)))
+            .collect::<Result<_, _>>()?;
+
+        Ok(program)
+    }
+
+    fn part1(program: &Vec<Instruction>) -> crate::Result<i32> {
+        part1(program)
+    }
+
+    fn part2(program: &Vec<Instruction>) -> crate::Result<Answer> {
+        part2(program)
+    }
+}
+
+impl Streaming for Day10 {
+    /// The screen for part two still grows by a pixel per cycle, so only
+    /// the program itself is streamed.
+    fn stream(input: impl BufRead) -> Streamed<i32, Answer> {
+        let mut state = MachineState::default();
+        let mut snoop = (CycleSnooper::default(), CRTSnooper::default());
+
+        stream::lines(input, |number, line| {
+            let instruction = line.parse().map_err(|err: ParseError| err.on_line(number))?;
+            execute(instruction, &mut state, &mut snoop);
+            Ok(())
+        })?;
+
+        let (cycles, crt) = snoop;
+        Ok((Ok(cycles.total_signal_strength), Ok(Answer::grid(&crt.screen))))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Instruction {
+    Addx(i32),
+    Noop,
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, ParseError> {
+        if let Some(value) = line.strip_prefix("addx ") {
+            Ok(Instruction::Addx(error::number(line, value)?))
+        } else if line == "noop" {
+            Ok(Instruction::Noop)
+        } else {
+            Err(ParseError::at(line, line, "expected `addx <value>` or `noop`"))
+        }
+    }
+}
+
+struct MachineState {
+    reg_x: i32,
+    cycle: i32,
+}
+
+impl Default for MachineState {
+    fn default() -> Self {
+        Self { reg_x: 1, cycle: 1 }
+    }
+}
+
+trait Visit {
+    fn during_cycle(&mut self, cycle_number: i32, state: &MachineState);
+}
+
+impl<A: Visit, B: Visit> Visit for (A, B) {
+    fn during_cycle(&mut self, cycle_number: i32, state: &MachineState) {
+        self.0.during_cycle(cycle_number, state);
+        self.1.during_cycle(cycle_number, state);
+    }
+}
+
+#[derive(Default)]
+struct CycleSnooper {
+    total_signal_strength: i32,
+}
+
+impl Visit for CycleSnooper {
+    fn during_cycle(&mut self, cycle_number: i32, state: &MachineState) {
+        if 0 != ((cycle_number - 20) % 40) {
+            return;
+        }
+
+        let signal_strength = cycle_number * state.reg_x;
+
+        self.total_signal_strength += signal_strength;
+    }
+}
+
+#[derive(Default)]
+struct CRTSnooper {
+    screen: String,
+}
+
+impl Visit for CRTSnooper {
+    fn during_cycle(&mut self, cycle_number: i32, state: &MachineState) {
+        let pixel = (cycle_number - 1) % 40;
+        if (state.reg_x - pixel).abs() < 2 {
+            self.screen.push('#');
+        } else {
+            self.screen.push('.');
+        }
+        if cycle_number % 40 == 0 {
+            self.screen.push('\n');
+        }
+    }
+}
+
+fn machine<V: Visit>(program: &[Instruction], snoop: &mut V) -> crate::Result<()> {
+    let mut state = MachineState::default();
+
+    for &instruction in program {
+        crate::cancel::check()?;
+        execute(instruction, &mut state, snoop);
+    }
+
+    Ok(())
+}
+
+fn execute<V: Visit>(instruction: Instruction, state: &mut MachineState, snoop: &mut V) {
+    match instruction {
+        Instruction::Addx(value) => {
+            snoop.during_cycle(state.cycle, state);
+            state.cycle += 1;
+            snoop.during_cycle(state.cycle, state);
+            state.cycle += 1;
+            state.reg_x += value;
+        }
+        Instruction::Noop => {
+            snoop.during_cycle(state.cycle, state);
+            state.cycle += 1;
+        }
+    }
+}
+
+fn part1(program: &[Instruction]) -> crate::Result<i32> {
+    let mut snoop = CycleSnooper::default();
+
+    machine(program, &mut snoop)?;
+
+    Ok(snoop.total_signal_strength)
+}
+
+fn part2(program: &[Instruction]) -> crate::Result<Answer> {
+    let mut snoop = CRTSnooper::default();
+
+    machine(program, &mut snoop)?;
+
+    Ok(Answer::grid(&snoop.screen))
+}
+
+#[test]
+fn test() {
+    let input = include_str!("../../input/2022/examples/day10-1.txt");
+    let program = Day10::parse(input).unwrap();
+
+    assert_eq!(Day10::part1(&program).unwrap(), 13140);
+    assert_eq!(
+        Day10::part2(&program).unwrap(),
+        Answer::grid("##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....")
+    );
+
+    let err = Day10::parse("noop\naddx 1\nmulx 2").unwrap_err();
+    assert_eq!(err.to_string(), "parse error: line 3, column 1: expected `addx <value>` or `noop`, found `mulx 2`");
+
+    let (part1, part2) = Day10::stream(input.as_bytes()).unwrap();
+    assert_eq!((part1.unwrap(), part2.unwrap()), (Day10::part1(&program).unwrap(), Day10::part2(&program).unwrap()));
+    let err = Day10::stream("noop\naddx 1\nmulx 2".as_bytes()).unwrap_err();
+    assert_eq!(err.to_string(), "parse error: line 3, column 1: expected `addx <value>` or `noop`, found `mulx 2`");
+}
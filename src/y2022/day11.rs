@@ -0,0 +1,218 @@
+use std::str::Lines;
+
+use crate::{
+    error::{self, Error, ParseError, Result},
+    Solution,
+};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Parsed = Vec<Monkey>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<Monkey>> {
+        let blocks: Vec<&str> = input.trim_end().split("\n\n").collect();
+        let mut monkies = vec![];
+
+        for block in &blocks {
+            let monkey = Monkey::try_from(*block).map_err(|err| err.within(input, block))?;
+
+            for target in [monkey.if_true, monkey.if_false] {
+                if target >= blocks.len() {
+                    let header = block.lines().next().unwrap();
+                    return Err(ParseError::at(input, header, format!("throws to monkey {target}, which does not exist")).into());
+                }
+            }
+
+            monkies.push(monkey);
+            if modulus(&monkies).is_none() {
+                let header = block.lines().next().unwrap();
+                return Err(ParseError::at(input, header, "its divisor makes the product of the divisors overflow").into());
+            }
+        }
+
+        Ok(monkies)
+    }
+
+    fn part1(monkies: &Vec<Monkey>) -> Result<i64> {
+        rounds(&mut monkies.clone(), 20, modulus(monkies).expect("checked by parse"))
+    }
+
+    fn part2(monkies: &Vec<Monkey>) -> Result<i64> {
+        rounds(&mut monkies.clone(), 10000, modulus(monkies).expect("checked by parse"))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Operation {
+    Add(usize),
+    Multiply(usize),
+    Square,
+}
+
+impl Operation {
+    fn apply(self, old: usize) -> Option<usize> {
+        match self {
+            Operation::Add(value) => old.checked_add(value),
+            Operation::Multiply(value) => old.checked_mul(value),
+            Operation::Square => old.checked_mul(old),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: Vec<usize>,
+    operation: Operation,
+    divisor: usize,
+    if_true: usize,
+    if_false: usize,
+    item_inspections: usize,
+}
+
+impl Monkey {
+    fn throw_to(&self, worry: usize) -> usize {
+        if worry.is_multiple_of(self.divisor) {
+            self.if_true
+        } else {
+            self.if_false
+        }
+    }
+}
+
+// The rest of the next line of `notes`, after `label` and any indentation.
+fn field<'a>(notes: &'a str, lines: &mut Lines<'a>, label: &str) -> std::result::Result<&'a str, ParseError> {
+    let Some(line) = lines.next() else {
+        return Err(ParseError::after(notes, notes, format!("expected `{label}`")));
+    };
+    let line = line.trim_start();
+
+    line.strip_prefix(label)
+        .ok_or_else(|| ParseError::at(notes, line, format!("expected `{label}`")))
+}
+
+impl TryFrom<&str> for Monkey {
+    type Error = ParseError;
+
+    fn try_from(notes: &str) -> std::result::Result<Self, ParseError> {
+        let mut lines = notes.lines();
+
+        field(notes, &mut lines, "Monkey ")?;
+
+        let items = field(notes, &mut lines, "Starting items:")?.trim_start();
+        let items = items
+            .split(", ")
+            .filter(|item| !item.is_empty())
+            .map(|item| error::number(notes, item))
+            .collect::<std::result::Result<_, _>>()?;
+
+        let operation = field(notes, &mut lines, "Operation: new = ")?;
+        let operation = match operation.split(' ').collect::<Vec<_>>()[..] {
+            ["old", "*", "old"] => Operation::Square,
+            ["old", "*", value] => Operation::Multiply(error::number(notes, value)?),
+            ["old", "+", value] => Operation::Add(error::number(notes, value)?),
+            _ => return Err(ParseError::at(notes, operation, "expected `old * <value>`, `old + <value>` or `old * old`")),
+        };
+
+        let divisor = field(notes, &mut lines, "Test: divisible by ")?;
+        let divisor = match error::number(notes, divisor)? {
+            0 => return Err(ParseError::at(notes, divisor, "expected a non-zero divisor")),
+            divisor => divisor,
+        };
+        let if_true = error::number(notes, field(notes, &mut lines, "If true: throw to monkey ")?)?;
+        let if_false = error::number(notes, field(notes, &mut lines, "If false: throw to monkey ")?)?;
+
+        Ok(Monkey {
+            items,
+            operation,
+            divisor,
+            if_true,
+            if_false,
+            item_inspections: 0,
+        })
+    }
+}
+
+/// The product of every divisor, or `None` if it overflows.
+fn modulus(monkies: &[Monkey]) -> Option<usize> {
+    monkies.iter().try_fold(1usize, |product, m| product.checked_mul(m.divisor))
+}
+
+fn take_turn(monkey_idx: usize, monkies: &mut [Monkey], divide: bool, modu: usize) -> Result<()> {
+    let monkey = monkies.get_mut(monkey_idx).unwrap();
+    let items = std::mem::take(&mut monkey.items);
+    let monkey = monkey.clone();
+    monkies[monkey_idx].item_inspections += items.len();
+
+    for item in items {
+        let item_worry_level = item;
+
+        let Some(item_worry_level) = monkey.operation.apply(item_worry_level) else {
+            return Err(Error::NoAnswer(format!("a worry level of {item_worry_level} overflows")));
+        };
+
+        let item_worry_level = if divide {
+            item_worry_level / 3
+        } else {
+            item_worry_level % modu
+        };
+
+        let next_monkey_index = monkey.throw_to(item_worry_level);
+
+        monkies
+            .get_mut(next_monkey_index)
+            .unwrap()
+            .items
+            .push(item_worry_level);
+    }
+
+    Ok(())
+}
+
+fn rounds(monkies: &mut [Monkey], rounds: usize, modu: usize) -> Result<i64> {
+    if monkies.len() < 2 {
+        return Err(Error::NoAnswer("fewer than two monkeys".to_owned()));
+    }
+
+
+    for _ in 0..rounds {
+        crate::cancel::check()?;
+        for monkey_index in 0..monkies.len() {
+            take_turn(monkey_index, monkies, rounds <= 20, modu)?;
+        }
+    }
+
+    let mut num_inspections: Vec<_> = monkies.iter().map(|m| m.item_inspections as i64).collect();
+    let last_two = num_inspections.len() - 2;
+    num_inspections.select_nth_unstable(last_two);
+    crate::debug!(2022, 11, "inspections after {rounds} rounds, two busiest last: {num_inspections:?}");
+    Ok(num_inspections[last_two..].iter().product())
+}
+
+#[test]
+fn test() {
+    let input = include_str!("../../input/2022/examples/day11-1.txt");
+
+    let monkies = Day11::parse(input).unwrap();
+
+    assert_eq!(monkies.len(), 4);
+    assert_eq!(monkies[1].items, [54, 65, 75, 74]);
+    assert_eq!(monkies[2].operation, Operation::Square);
+    assert_eq!(monkies[3].throw_to(34), 0);
+    assert_eq!(modulus(&monkies), Some(96577));
+
+    assert_eq!(Day11::part1(&monkies).unwrap(), 10605);
+    assert_eq!(Day11::part2(&monkies).unwrap(), 2713310158);
+
+    let err = Day11::parse(&input.replace("old + 6", "old - 6")).unwrap_err();
+    assert_eq!(err.to_string(), "parse error: line 10, column 20: expected `old * <value>`, `old + <value>` or `old * old`, found `old - 6`");
+    let err = Day11::parse(&input.replace("monkey 3", "monkey 4")).unwrap_err();
+    assert_eq!(err.to_string(), "parse error: line 1, column 1: throws to monkey 4, which does not exist, found `Monkey 0:`");
+    let err = Day11::parse(&input.replace("divisible by 13", "divisible by 9223372036854775807")).unwrap_err();
+    assert_eq!(err.to_string(), "parse error: line 15, column 1: its divisor makes the product of the divisors overflow, found `Monkey 2:`");
+
+    let monkies = Day11::parse(&input.replace("old * 19", "old * 9223372036854775807")).unwrap();
+    assert_eq!(Day11::part1(&monkies).unwrap_err().to_string(), "no answer: a worry level of 79 overflows");
+}
@@ -0,0 +1,318 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+
+use crate::{
+    error::{Error, ParseError, Result},
+    solution::Variant,
+    Pos2d, Solution,
+};
+
+pub struct Day12;
+
+/// A validated map of heights `a`-`z`, with one start `S` and one goal `E`.
+#[derive(Debug)]
+pub struct Heightmap {
+    map: String,
+    size: (usize, usize),
+}
+
+impl Solution for Day12 {
+    type Parsed = Heightmap;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    const PART1_VARIANTS: &'static [Variant<Heightmap, i32>] =
+        &[("bfs", |heightmap| distances_to_goal(heightmap)?[index(heightmap, heightmap.start())].ok_or_else(no_path))];
+    const PART2_VARIANTS: &'static [Variant<Heightmap, i32>] = &[("bfs", |heightmap| {
+        let distances = distances_to_goal(heightmap)?;
+        let lowest = heightmap.map.bytes().enumerate().filter(|&(_, c)| c == b'a' || c == b'S');
+        lowest.filter_map(|(i, _)| distances[i]).min().ok_or_else(no_path)
+    })];
+
+    fn parse(input: &str) -> Result<Heightmap> {
+        Ok(Heightmap::new(input)?)
+    }
+
+    fn part1(heightmap: &Heightmap) -> Result<i32> {
+        part1(&heightmap.map, heightmap.size)?.ok_or_else(no_path)
+    }
+
+    fn part2(heightmap: &Heightmap) -> Result<i32> {
+        part2(&heightmap.map, heightmap.size)?.ok_or_else(no_path)
+    }
+}
+
+impl Heightmap {
+    pub fn new(input: &str) -> std::result::Result<Heightmap, ParseError> {
+        let map = input.trim_end();
+        let size = (map.lines().next().map_or(0, str::len), map.lines().count());
+
+        for line in map.lines() {
+            if let Some((i, c)) = line.char_indices().find(|(_, c)| !matches!(c, 'a'..='z' | 'S' | 'E')) {
+                return Err(ParseError::at(input, &line[i..i + c.len_utf8()], "expected a height a-z, S or E"));
+            }
+            if line.len() != size.0 {
+                return Err(ParseError::at(input, line, format!("expected {} squares per row", size.0)));
+            }
+        }
+
+        for marker in ["S", "E"] {
+            match map.matches(marker).count() {
+                0 => return Err(ParseError::after(input, map, format!("expected a square marked {marker}"))),
+                1 => {}
+                _ => {
+                    let second = map.match_indices(marker).nth(1).unwrap().0;
+                    return Err(ParseError::at(input, &map[second..second + 1], format!("expected one square marked {marker}")));
+                }
+            }
+        }
+
+        Ok(Heightmap { map: map.to_owned(), size })
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        self.size
+    }
+
+    /// Height from 0 (`a` and `S`) to 26 (`E`, which only `z` can climb to).
+    pub fn height(&self, position: Pos2d) -> Option<u8> {
+        let inside = (0..self.size.0 as i32).contains(&position.x) && (0..self.size.1 as i32).contains(&position.y);
+
+        inside.then(|| get(self.map.as_bytes(), self.size, &position).0)
+    }
+
+    pub fn start(&self) -> Pos2d {
+        self.find('S')
+    }
+
+    pub fn goal(&self) -> Pos2d {
+        self.find('E')
+    }
+
+    /// The fewest steps from any of `start` to `goal`, climbing at most one
+    /// level per step.
+    pub fn shortest_path(&self, start: &[Pos2d], goal: Pos2d) -> Result<Option<i32>> {
+        shortest_path(self.map.as_bytes(), self.size, start, goal)
+    }
+
+    fn find(&self, marker: char) -> Pos2d {
+        let i = self.map.find(marker).unwrap() as i32;
+        let width = self.size.0 as i32 + 1;
+
+        Pos2d::new(i % width, i / width)
+    }
+}
+
+fn no_path() -> Error {
+    Error::NoAnswer("no path reaches E".to_owned())
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+struct State {
+    cost: i32,
+    position: Pos2d,
+}
+
+// The priority queue depends on `Ord`.
+// Explicitly implement the trait so the queue becomes a min-heap
+// instead of a max-heap.
+impl Ord for State {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Notice that the we flip the ordering on costs.
+        // In case of a tie we compare positions - this step is necessary
+        // to make implementations of `PartialEq` and `Ord` consistent.
+        other
+            .cost
+            .cmp(&self.cost)
+            .then_with(|| self.position.cmp(&other.position))
+    }
+}
+
+// `PartialOrd` needs to be implemented as well.
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Dijkstra's shortest path algorithm.
+
+// Start at `start` and use `dist` to track the current shortest distance
+// to each node. This implementation isn't memory-efficient as it may leave duplicate
+// nodes in the queue. It also uses `usize::MAX` as a sentinel value,
+// for a simpler implementation.
+fn shortest_path(map: &[u8], size: (usize, usize), start: &[Pos2d], goal: Pos2d) -> Result<Option<i32>> {
+    crate::trace!(2022, 12, "searching from {start:?} to {goal:?}");
+
+    // dist[node] = current shortest distance from `start` to `node`
+    let mut dist: HashMap<Pos2d, i32> = Default::default();
+
+    let mut heap = BinaryHeap::new();
+
+    // We're at `start`, with a zero cost
+    for start in start {
+        dist.insert(*start, 0);
+        heap.push(State {
+            cost: 0,
+            position: *start,
+        });
+    }
+
+    // Examine the frontier with lower cost nodes first (min-heap)
+    while let Some(State { cost, position }) = heap.pop() {
+        crate::cancel::check()?;
+
+        // Alternatively we could have continued to find all shortest paths
+        if position == goal {
+            return Ok(Some(cost));
+        }
+
+        // Important as we may have already found a better way
+        if dist.contains_key(&position) && cost > *dist.get(&position).unwrap() {
+            continue;
+        }
+
+        // For each node we can reach, see if we can find a way with
+        // a lower cost going through this node
+        for edge in edges(map, size, &position) {
+            let next = State {
+                cost: cost + 1,
+                position: edge,
+            };
+
+            // If so, add it to the frontier and continue
+            if !dist.contains_key(&next.position) || next.cost < *dist.get(&next.position).unwrap()
+            {
+                heap.push(next);
+                // Relaxation, we have now found a better way
+                dist.insert(next.position, next.cost);
+            }
+        }
+    }
+
+    if crate::log::enabled(2022, 12, crate::log::Level::Debug) {
+        let reached: Vec<String> = (0..size.1 as i32)
+            .map(|y| (0..size.0 as i32).map(|x| dist.get(&Pos2d { x, y }).map_or('.', |d| char::from(b'0' + (d % 10) as u8))).collect())
+            .collect();
+        crate::debug!(2022, 12, "no path, last digit of the distance to each square reached:\n{}", reached.join("\n"));
+    }
+
+    // Goal not reachable
+    Ok(None)
+}
+
+// Breadth-first search backwards from E: every step costs the same, so
+// there is no need for a priority queue, and one search answers both parts.
+// Distances are indexed like the map's bytes, newlines included.
+fn distances_to_goal(heightmap: &Heightmap) -> Result<Vec<Option<i32>>> {
+    let mut distances = vec![None; heightmap.map.len()];
+    let mut queue = VecDeque::from([(heightmap.goal(), 0)]);
+    distances[index(heightmap, heightmap.goal())] = Some(0);
+
+    while let Some((position, distance)) = queue.pop_front() {
+        crate::cancel::check()?;
+        let height = heightmap.height(position).unwrap();
+
+        for previous in [position.left(), position.right(), position.down(), position.up()] {
+            let Some(previous_height) = heightmap.height(previous) else {
+                continue;
+            };
+            let slot = &mut distances[index(heightmap, previous)];
+            if height <= previous_height + 1 && slot.is_none() {
+                *slot = Some(distance + 1);
+                queue.push_back((previous, distance + 1));
+            }
+        }
+    }
+
+    Ok(distances)
+}
+
+fn index(heightmap: &Heightmap, position: Pos2d) -> usize {
+    (position.y * (heightmap.size.0 as i32 + 1) + position.x) as usize
+}
+
+fn get(map: &[u8], size: (usize, usize), position: &Pos2d) -> (u8,char) {
+    let size = size.0 as i32;
+    let &c = map
+        .get((position.y * (size + 1) + position.x) as usize)
+        .unwrap();
+    if c == b'S' {
+        (0, 'S')
+    } else if c == b'E' {
+        (26, 'E')
+    } else {
+        (c - b'a', '\0')
+    }
+}
+
+fn edges(map: &[u8], size: (usize, usize), position: &Pos2d) -> Vec<Pos2d> {
+    [
+        position.left(),
+        position.right(),
+        position.down(),
+        position.up(),
+    ]
+    .into_iter()
+    .filter(|p| p.x >= 0 && p.y >= 0 && p.y < size.1 as i32 && p.x < size.0 as i32)
+    .filter(|p| (get(map, size, p).0) <= get(map, size, position).0+1)
+    .collect()
+}
+
+fn part1(input: &str, size: (usize, usize)) -> Result<Option<i32>> {
+
+    let map = input.as_bytes();
+    let mut start = Pos2d::default();
+    let mut goal = Pos2d::default();
+
+    for x in 0..size.0 as i32 {
+        for y in 0..size.1 as i32 {
+            if get(map, size, &Pos2d { x, y }).1 == 'S' {
+                start = Pos2d { x, y };
+            } else if get(map, size, &Pos2d { x, y }).1 == 'E' {
+                goal = Pos2d { x, y };
+            }
+        }
+    }
+
+    shortest_path(map, size, &[start], goal)
+} 
+fn part2(input: &str, size: (usize, usize)) -> Result<Option<i32>> {
+
+    let map = input.as_bytes();
+    let mut start = Vec::default();
+    let mut goal = Pos2d::default();
+
+    for x in 0..size.0 as i32 {
+        for y in 0..size.1 as i32 {
+            if get(map, size, &Pos2d { x, y }).0 == 0 {
+                start.push(Pos2d{ x, y });
+            } else if get(map, size, &Pos2d { x, y }).1 == 'E' {
+                goal = Pos2d { x, y };
+            }
+        }
+    }
+
+    shortest_path(map, size, &start, goal)
+} 
+#[test]
+fn test() {
+    let input = include_str!("../../input/2022/examples/day12-1.txt");
+    let size = (8,5);
+
+    assert_eq!(part1(input, size).unwrap(), Some(31));
+    assert_eq!(part2(input, size).unwrap(), Some(29));
+
+    let heightmap = Day12::parse(input).unwrap();
+    assert_eq!(heightmap.size(), size);
+    assert_eq!(Day12::part1(&heightmap).unwrap(), 31);
+    assert_eq!((heightmap.start(), heightmap.goal()), (Pos2d::new(0, 0), Pos2d::new(5, 2)));
+    assert_eq!(heightmap.height(Pos2d::new(2, 1)), Some(2));
+    assert_eq!(heightmap.height(Pos2d::new(8, 0)), None);
+    assert_eq!(heightmap.shortest_path(&[Pos2d::new(4, 2)], heightmap.goal()).unwrap(), Some(1));
+    assert_eq!(distances_to_goal(&heightmap).unwrap()[index(&heightmap, heightmap.start())], Some(31));
+
+    let err = Day12::parse(&input.replace('E', "z")).unwrap_err();
+    assert_eq!(err.to_string(), "parse error: line 5, column 9: expected a square marked E, found nothing");
+    assert!(matches!(Day12::part1(&Day12::parse("Sz\nzE").unwrap()), Err(Error::NoAnswer(_))));
+}
@@ -0,0 +1,225 @@
+use std::{
+    cmp::Ordering::Equal,
+    iter::Peekable,
+    str::{CharIndices, FromStr},
+};
+
+use crate::{
+    error::{self, ParseError, Result},
+    Solution,
+};
+
+pub struct Day13;
+
+/// How deeply lists may nest in a packet, so a hostile line cannot overflow
+/// the stack.
+const MAX_DEPTH: usize = 128;
+
+impl Solution for Day13 {
+    type Parsed = Vec<Value>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<Value>> {
+        let lines: Vec<&str> = input.lines().filter(|line| !line.is_empty()).collect();
+
+        if lines.len() % 2 == 1 {
+            return Err(ParseError::after(input, input, "expected a second packet to pair with the last").into());
+        }
+
+        let packets = lines
+            .into_iter()
+            .map(|line| Value::try_from(line).map_err(|err| err.within(input, line)))
+            .collect::<std::result::Result<_, _>>()?;
+
+        Ok(packets)
+    }
+
+    fn part1(packets: &Vec<Value>) -> Result<i32> {
+        part1(packets)
+    }
+
+    fn part2(packets: &Vec<Value>) -> Result<i32> {
+        part2(packets)
+    }
+}
+
+/// A distress signal packet. Ordered as the puzzle's "right order" rules,
+/// with a literal compared to a list as if it were a one-item list.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Value {
+    Literal(i32),
+    List(Vec<Value>),
+}
+
+fn unexpected(source: &str, next: Option<(usize, char)>, message: &str) -> ParseError {
+    match next {
+        Some((i, c)) => ParseError::at(source, &source[i..i + c.len_utf8()], message),
+        None => ParseError::after(source, source, message),
+    }
+}
+
+/// Parses the value at `input`, within `depth` lists.
+fn parse_value(source: &str, input: &mut Peekable<CharIndices>, depth: usize) -> std::result::Result<Value, ParseError> {
+    match input.next() {
+        Some((i, '[')) if depth == MAX_DEPTH => Err(ParseError::at(source, &source[i..i + 1], "expected less deeply nested lists")),
+        Some((_, '[')) => {
+            let mut values = vec![];
+            loop {
+                if input.peek().map(|&(_, c)| c) == Some(']') {
+                    input.next().unwrap();
+                    break;
+                }
+                let value = parse_value(source, input, depth + 1)?;
+                values.push(value);
+
+                match input.next() {
+                    Some((_, ']')) => break,
+                    Some((_, ',')) => continue,
+                    next => return Err(unexpected(source, next, "expected `,` or `]`")),
+                };
+            }
+
+            Ok(Value::List(values))
+        }
+        Some((start, '0'..='9')) => {
+            let mut end = start + 1;
+            while let Some(&(i, next)) = input.peek() {
+                if !next.is_ascii_digit() {
+                    break;
+                }
+                input.next().unwrap();
+                end = i + 1;
+            }
+            Ok(Value::Literal(error::number(source, &source[start..end])?))
+        }
+        next => Err(unexpected(source, next, "expected `[` or a number")),
+    }
+}
+
+impl TryFrom<&str> for Value {
+    type Error = ParseError;
+
+    fn try_from(packet: &str) -> std::result::Result<Self, ParseError> {
+        let mut input = packet.char_indices().peekable();
+        let value = parse_value(packet, &mut input, 0)?;
+
+        match input.next() {
+            None => Ok(value),
+            next => Err(unexpected(packet, next, "expected end of packet")),
+        }
+    }
+}
+
+impl FromStr for Value {
+    type Err = ParseError;
+
+    fn from_str(packet: &str) -> std::result::Result<Self, ParseError> {
+        Value::try_from(packet)
+    }
+}
+
+impl Ord for Value {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (Value::Literal(a), Value::Literal(b)) => a.cmp(b),
+            (Value::Literal(l), Value::List(_)) => Value::List(vec![Value::Literal(*l)]).cmp(other),
+            (Value::List(_), Value::Literal(l)) => self.cmp(&Value::List(vec![Value::Literal(*l)])),
+            (Value::List(left), Value::List(right)) => {
+                for (left, right) in left.iter().zip(right.iter()) {
+                    match left.cmp(right) {
+                        Equal => continue,
+                        a => return a,
+                    };
+                }
+                left.len().cmp(&right.len())
+            }
+        }
+    }
+}
+
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn part1(packets: &[Value]) -> Result<i32> {
+    let mut count = 0;
+
+    for (pair, index) in packets.chunks(2).zip(1..) {
+        crate::cancel::check()?;
+        let left = &pair[0];
+        let right = &pair[1];
+
+        let right_order = left <= right;
+        crate::trace!(2022, 13, "pair {index} in the right order: {right_order}");
+        if right_order {
+            count+= index;
+        }
+    }
+
+    Ok(count)
+}
+
+// Finds where the dividers would land in the sorted packets by counting the
+// packets that sort before each, rather than sorting, so every comparison
+// happens in a loop that can be cancelled.
+fn part2(packets: &[Value]) -> Result<i32> {
+    let divider = |n| Value::List(vec![Value::List(vec![Value::Literal(n)])]);
+    let (divider1, divider2) = (divider(2), divider(6));
+
+    // Positions count from 1, and the first divider sorts before the second.
+    let (mut position1, mut position2) = (1, 2);
+    for packet in packets {
+        crate::cancel::check()?;
+        if *packet < divider1 {
+            position1 += 1;
+        }
+        if *packet < divider2 {
+            position2 += 1;
+        }
+    }
+
+    Ok(position1 * position2)
+}
+
+#[test]
+fn test() {
+    let value = |packet| Value::try_from(packet).unwrap();
+
+    assert_eq!(Value::Literal(1), value("1"));
+    assert_eq!(Value::List(vec![Value::Literal(1)]), value("[1]"));
+    assert_eq!(
+        Value::List(vec![Value::Literal(1), Value::Literal(1)]),
+        value("[1,1]")
+    );
+
+    assert_eq!(
+        value("[1,2,3,[1,2]]"),
+        Value::List(vec![value("1"), value("2"), value("3"), value("[1,2]")])
+    );
+
+    assert_eq!(Value::List(vec![]), value("[]"));
+
+    assert!(value("[[4,4],4,4]") < value("[[4,4],4,4,4]"));
+    assert!(value("[1,[2,[3,[4,[5,6,7]]]],8,9]") > value("[1,[2,[3,[4,[5,6,0]]]],8,9]"));
+
+    let input = include_str!("../../input/2022/examples/day13-1.txt");
+
+    assert_eq!(Day13::part1(&Day13::parse(input).unwrap()).unwrap(), 13);
+    assert_eq!(Day13::part2(&Day13::parse(input).unwrap()).unwrap(), 140);
+    assert!("[[1],[2,3,4]]".parse::<Value>().unwrap() < "[[1],4]".parse().unwrap());
+
+    let err = Day13::parse("[1,2]\n[1;2]").unwrap_err();
+    assert_eq!(err.to_string(), "parse error: line 2, column 3: expected `,` or `]`, found `;`");
+    let err = Day13::parse("[1,2]\n[1,2").unwrap_err();
+    assert_eq!(err.to_string(), "parse error: line 2, column 5: expected `,` or `]`, found nothing");
+    let err = Day13::parse("[1]\n[2]]").unwrap_err();
+    assert_eq!(err.to_string(), "parse error: line 2, column 4: expected end of packet, found `]`");
+
+    let nested = format!("{}{}", "[".repeat(MAX_DEPTH), "]".repeat(MAX_DEPTH));
+    assert!(nested.parse::<Value>().is_ok());
+    let err = Day13::parse(&format!("[]\n{}", "[".repeat(200_000))).unwrap_err();
+    assert_eq!(err.to_string(), "parse error: line 2, column 129: expected less deeply nested lists, found `[`");
+}
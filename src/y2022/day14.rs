@@ -0,0 +1,155 @@
+use std::collections::HashSet;
+
+use crate::{
+    error::{ParseError, Result},
+    pos2d::Pos2d,
+    Solution,
+};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Parsed = LocationSet;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<LocationSet> {
+        let wl = walls(input)?;
+
+        if wl.is_empty() {
+            return Err(ParseError::after(input, input, "expected at least one wall").into());
+        }
+
+        Ok(wl)
+    }
+
+    fn part1(wl: &LocationSet) -> Result<i32> {
+        solve(wl, RecursionDecision::Sentinal)
+    }
+
+    fn part2(wl: &LocationSet) -> Result<i32> {
+        solve(wl, RecursionDecision::Continue)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy)]
+enum RecursionDecision {
+    Sentinal,
+    Continue,
+}
+
+pub type LocationSet = HashSet<Pos2d>;
+
+// Fills sand from `start` depth first, as the recursive version would, but
+// keeps its own stack so a deep pit cannot overflow the thread's stack.
+fn recursion(start: Pos2d, wl: &LocationSet, ssl: &mut LocationSet, pit_begins: i32, pit_decision: RecursionDecision) -> Result<RecursionDecision> {
+    if let Some(decision) = base_case(start, wl, ssl, pit_begins, pit_decision) {
+        return Ok(decision);
+    }
+
+    // Each frame is a location and how many of the places below it
+    // (down, down left, down right) have been tried.
+    let mut stack = vec![(start, 0)];
+
+    while let Some((location, tried)) = stack.last_mut() {
+        crate::cancel::check()?;
+
+        let next = match tried {
+            0 => location.down(),
+            1 => location.down().left(),
+            2 => location.down().right(),
+            // None of them fell into the pit: place sand here in the ssl set.
+            _ => {
+                ssl.insert(*location);
+                stack.pop();
+                continue;
+            }
+        };
+        *tried += 1;
+
+        match base_case(next, wl, ssl, pit_begins, pit_decision) {
+            // Sand falls through every location on the stack.
+            Some(RecursionDecision::Sentinal) => return Ok(RecursionDecision::Sentinal),
+            Some(RecursionDecision::Continue) => {}
+            None => stack.push((next, 0)),
+        }
+    }
+
+    Ok(RecursionDecision::Continue)
+}
+
+// What `recursion` decides for `location` without looking further, if anything.
+fn base_case(location: Pos2d, wl: &LocationSet, ssl: &LocationSet, pit_begins: i32, pit_decision: RecursionDecision) -> Option<RecursionDecision> {
+    // (global) base case check. are we now in the bottomless pit?
+    // -> return Sentinal.
+    if location.y >= pit_begins {
+        return Some(pit_decision);
+    }
+
+    // base case check. are we now in a wall, or already contains sand?
+    // -> return Continue.
+    if wl.contains(&location) || ssl.contains(&location) {
+        return Some(RecursionDecision::Continue);
+    }
+
+    None
+}
+
+// Parse input into wall locations set (wl)
+fn walls(input: &str) -> std::result::Result<LocationSet, ParseError> {
+    let mut wl = LocationSet::default();
+
+    let coords = |part: &str| part.parse::<Pos2d>().map_err(|err| err.within(input, part));
+
+    for line in input.lines() {
+        let mut parts = line.split("->");
+        let mut pos = coords(parts.next().unwrap())?;
+
+        for part in parts {
+            let part_pos = coords(part)?;
+            let Some(direction) = pos.cmp_no_diagonal(&part_pos) else {
+                return Err(ParseError::at(input, part.trim(), "expected a horizontal or vertical wall"));
+            };
+
+            wl.insert(pos);
+            while pos != part_pos {
+                pos = pos.move_in_direction(direction);
+                wl.insert(pos);
+            }
+        }
+    }
+
+    Ok(wl)
+}
+
+fn solve(wl: &LocationSet, pit_decision: RecursionDecision) -> Result<i32> {
+    // Create empty stationary sand locations set (ssl)
+    let mut ssl = LocationSet::default();
+
+    // calculate depth of bottomless pit +1
+    let pit_begins = 2 + wl.iter().map(|p| p.y).max().unwrap();
+
+    recursion(Pos2d { x: 500, y: 0 }, wl, &mut ssl, pit_begins, pit_decision)?;
+
+    Ok(ssl.len() as i32)
+}
+
+#[test]
+fn test() {
+    let input = include_str!("../../input/2022/examples/day14-1.txt");
+
+    assert_eq!(Day14::part1(&Day14::parse(input).unwrap()).unwrap(), 24);
+    assert_eq!(Day14::part2(&Day14::parse(input).unwrap()).unwrap(), 93);
+
+    let expired = crate::cancel::Token::new(Some(std::time::Duration::ZERO));
+    let err = crate::cancel::within(&expired, || Day14::part2(&Day14::parse(input).unwrap())).unwrap_err();
+    assert!(matches!(err, crate::Error::TimedOut(_)));
+
+    // Far deeper than the recursive fill could go on a test thread's stack.
+    assert_eq!(Day14::part1(&Day14::parse("499,200000 -> 501,200000").unwrap()).unwrap(), 1);
+
+    let err = Day14::parse("498,4 -> 498,6 -> 496,7").unwrap_err();
+    assert_eq!(err.to_string(), "parse error: line 1, column 19: expected a horizontal or vertical wall, found `496,7`");
+    let err = Day14::parse("498,4 -> 498,x").unwrap_err();
+    assert_eq!(err.to_string(), "parse error: line 1, column 14: expected a number, found `x`");
+}